@@ -0,0 +1,181 @@
+//! Benchmarks parsing & each part of a day separately over repeated runs.
+//!
+//! The results are printed as table & can be written to a CSV file, one line per day & stage,
+//! to compare the timings between commits.
+
+use std::{
+    fmt::Write as _,
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use aoc_common::solution::Solution;
+
+use crate::format_duration;
+
+/// The timings of a single stage, e.g. parsing the input, over all runs.
+pub struct Timing {
+    /// The measured stage, `parse`, `part1` or `part2`
+    pub stage: &'static str,
+    /// The sorted durations of all runs
+    pub samples: Vec<Duration>,
+}
+
+impl Timing {
+    fn new(stage: &'static str, mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        Self { stage, samples }
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples.first().copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples.last().copied().unwrap_or_default()
+    }
+
+    /// The middle sample, the mean of both middle samples for an even number of runs.
+    pub fn median(&self) -> Duration {
+        let len = self.samples.len();
+        match len {
+            0 => Duration::ZERO,
+            _ if len % 2 == 1 => self.samples[len / 2],
+            _ => (self.samples[len / 2 - 1] + self.samples[len / 2]) / 2,
+        }
+    }
+}
+
+/// Measures parsing & all parts of the solution with the default config `runs` times.
+pub fn measure<S: Solution>(input: &str, runs: u32) -> anyhow::Result<Vec<Timing>> {
+    let config = S::Config::default();
+    let mut samples = vec![Vec::with_capacity(runs as usize); 1 + S::PARTS as usize];
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        // keeps the compiler from optimising away the work of cheap days
+        let parsed = black_box(S::parse(black_box(input), &config)?);
+        samples[0].push(start.elapsed());
+
+        for part in 1..=S::PARTS {
+            let start = Instant::now();
+            black_box(S::part(&parsed, part, &config)?);
+            samples[part as usize].push(start.elapsed());
+        }
+    }
+
+    let stages = ["parse", "part1", "part2"];
+    Ok(stages
+        .into_iter()
+        .zip(samples)
+        .map(|(stage, samples)| Timing::new(stage, samples))
+        .collect())
+}
+
+/// The benchmark results of a single day.
+pub struct Report {
+    pub day: u8,
+    pub timings: anyhow::Result<Vec<Timing>>,
+}
+
+/// Prints the table of all timings, a failed day shows its error.
+pub fn print_table(reports: &[Report]) {
+    println!(
+        "{:>3} | {:<5} | {:>4} | {:>10} | {:>10} | {:>10}",
+        "Day", "Stage", "Runs", "Min", "Median", "Max"
+    );
+    println!(
+        "{:-<3}-+-{:-<5}-+-{:-<4}-+-{:-<10}-+-{:-<10}-+-{:-<10}",
+        "", "", "", "", "", ""
+    );
+
+    for report in reports {
+        match &report.timings {
+            Ok(timings) => {
+                for timing in timings {
+                    println!(
+                        "{:>3} | {:<5} | {:>4} | {:>10} | {:>10} | {:>10}",
+                        report.day,
+                        timing.stage,
+                        timing.samples.len(),
+                        format_duration(timing.min()),
+                        format_duration(timing.median()),
+                        format_duration(timing.max()),
+                    );
+                }
+            }
+            Err(err) => println!("{:>3} | error: {:#}", report.day, err),
+        }
+    }
+}
+
+/// Renders the timings as CSV, the durations are given in nanoseconds.
+pub fn to_csv(reports: &[Report]) -> String {
+    let mut csv = String::from("day,stage,runs,min_ns,median_ns,max_ns\n");
+    for report in reports {
+        for timing in report.timings.iter().flatten() {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{}",
+                report.day,
+                timing.stage,
+                timing.samples.len(),
+                timing.min().as_nanos(),
+                timing.median().as_nanos(),
+                timing.max().as_nanos(),
+            );
+        }
+    }
+    csv
+}
+
+/// Writes the timings as CSV file.
+pub fn write_csv(path: &Path, reports: &[Report]) -> anyhow::Result<()> {
+    fs::write(path, to_csv(reports))
+        .with_context(|| format!("Failed to write benchmark results '{}'", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(samples: &[u64]) -> Timing {
+        Timing::new(
+            "part1",
+            samples
+                .iter()
+                .map(|&ms| Duration::from_millis(ms))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn check_statistics() {
+        let timing = millis(&[5, 1, 3]);
+        assert_eq!(Duration::from_millis(1), timing.min());
+        assert_eq!(Duration::from_millis(3), timing.median());
+        assert_eq!(Duration::from_millis(5), timing.max());
+        assert_eq!(Duration::from_millis(3), millis(&[4, 1, 2, 8]).median());
+    }
+
+    #[test]
+    fn check_csv() {
+        let reports = vec![
+            Report {
+                day: 3,
+                timings: Ok(vec![millis(&[2, 1])]),
+            },
+            Report {
+                day: 4,
+                timings: Err(anyhow::anyhow!("Failed")),
+            },
+        ];
+        assert_eq!(
+            "day,stage,runs,min_ns,median_ns,max_ns\n3,part1,2,1000000,1500000,2000000\n",
+            to_csv(&reports)
+        );
+    }
+}
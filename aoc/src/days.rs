@@ -0,0 +1,76 @@
+//! Registry of all puzzle days, maps each day to its bundled input & solution.
+
+use aoc_common::solution::{Answer, Solution};
+
+use crate::bench::{self, Timing};
+
+/// Parses the puzzle input and solves the given part with the default config.
+pub type Solver = fn(&str, u8) -> anyhow::Result<Answer>;
+
+/// Measures parsing & each part of the puzzle input over the given number of runs.
+pub type Bencher = fn(&str, u32) -> anyhow::Result<Vec<Timing>>;
+
+/// A single puzzle day with the path to its bundled input and its solution.
+pub struct Day {
+    /// The day of the puzzle, starts at `1`
+    pub day: u8,
+    /// Path to the bundled puzzle input of this day
+    pub input: &'static str,
+    /// The number of parts, the last day only has a single part.
+    pub parts: u8,
+    /// Solves a single part of this day
+    pub solver: Solver,
+    /// Benchmarks this day
+    pub bencher: Bencher,
+}
+
+impl Day {
+    /// Registers the solution of a day with its bundled input.
+    const fn new<S: Solution>(input: &'static str) -> Self {
+        Self {
+            day: S::DAY,
+            input,
+            parts: S::PARTS,
+            solver: solve::<S>,
+            bencher: bench::measure::<S>,
+        }
+    }
+}
+
+/// Solves the part with the default config of the solution.
+fn solve<S: Solution>(input: &str, part: u8) -> anyhow::Result<Answer> {
+    S::solve(input, part, &S::Config::default())
+}
+
+pub static DAYS: [Day; 25] = [
+    Day::new::<day01::Day01>(day01::INPUT_PATH),
+    Day::new::<day02::Day02>(day02::INPUT_PATH),
+    Day::new::<day03::Day03>(day03::INPUT_PATH),
+    Day::new::<day04::Day04>(day04::INPUT_PATH),
+    Day::new::<day05::Day05>(day05::INPUT_PATH),
+    Day::new::<day06::Day06>(day06::INPUT_PATH),
+    Day::new::<day07::Day07>(day07::INPUT_PATH),
+    Day::new::<day08::Day08>(day08::INPUT_PATH),
+    Day::new::<day09::Day09>(day09::INPUT_PATH),
+    Day::new::<day10::Day10>(day10::INPUT_PATH),
+    Day::new::<day11::Day11>(day11::INPUT_PATH),
+    Day::new::<day12::Day12>(day12::INPUT_PATH),
+    Day::new::<day13::Day13>(day13::INPUT_PATH),
+    Day::new::<day14::Day14>(day14::INPUT_PATH),
+    Day::new::<day15::Day15>(day15::INPUT_PATH),
+    Day::new::<day16::Day16>(day16::INPUT_PATH),
+    Day::new::<day17::Day17>(day17::INPUT_PATH),
+    Day::new::<day18::Day18>(day18::INPUT_PATH),
+    Day::new::<day19::Day19>(day19::INPUT_PATH),
+    Day::new::<day20::Day20>(day20::INPUT_PATH),
+    Day::new::<day21::Day21>(day21::INPUT_PATH),
+    Day::new::<day22::Day22>(day22::INPUT_PATH),
+    Day::new::<day23::Day23>(day23::INPUT_PATH),
+    Day::new::<day24::Day24>(day24::INPUT_PATH),
+    Day::new::<day25::Day25>(day25::INPUT_PATH),
+];
+
+/// Returns the registered day, `None` if there is no such day.
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
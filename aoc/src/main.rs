@@ -0,0 +1,449 @@
+//! Runner for all Advent of Code 2022 puzzles.
+//!
+//! Solves a single day or all days at once and prints a summary table of all answers
+//! together with the time it took to compute them. Each answer is checked against the
+//! expected answers file next to the puzzle input, the answers can also be printed as JSON.
+//! All days are solved concurrently on a thread pool by default, a panicking day is reported
+//! as error without aborting the others. The `bench` command measures parsing & each part
+//! separately over repeated runs.
+
+mod bench;
+mod days;
+
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{
+    expected::{Expected, Verdict},
+    input::Source,
+    solution::Answer,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use rayon::prelude::*;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use days::Day;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2022 puzzles")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Enables logging to stderr, e.g. `debug` or `day22::cuboid=trace`
+    #[arg(long, global = true, value_name = "FILTER")]
+    log: Option<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solves a single day or all days, prints a summary table
+    Run {
+        /// The day to solve
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+        day: Option<u8>,
+        /// Solve all days
+        #[arg(short, long, conflicts_with = "day")]
+        all: bool,
+        /// Only solve the given part, solves both parts by default
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Path to the puzzle input, `-` reads from stdin, uses the bundled input by default
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+        /// The output format of the answers
+        #[arg(short, long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+        /// Solves the days concurrently on this many threads, `0` uses one thread per core &
+        /// `1` solves the days one after another
+        #[arg(short, long, value_name = "THREADS", default_value_t = 0)]
+        jobs: usize,
+    },
+    /// Benchmarks parsing & each part of a single day or all days
+    Bench {
+        /// The day to benchmark
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+        day: Option<u8>,
+        /// Benchmark all days
+        #[arg(short, long, conflicts_with = "day")]
+        all: bool,
+        /// The number of runs per day
+        #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Path to the puzzle input, `-` reads from stdin, uses the bundled input by default
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Writes the timings to this CSV file
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// The output format of the `run` command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// A summary table for humans
+    Table,
+    /// A JSON array with one object per day & part
+    Json,
+}
+
+/// Returns the selected days, all days or the single given day.
+fn select_days(day: Option<u8>, all: bool) -> Vec<&'static Day> {
+    if all {
+        days::DAYS.iter().collect()
+    } else {
+        day.and_then(days::get).into_iter().collect()
+    }
+}
+
+/// Returns the source of the puzzle input, the bundled input of the day by default.
+fn select_source(day: &Day, input: Option<&Path>) -> Source {
+    match input {
+        Some(path) => Source::select(Some(path), day.input),
+        None => Source::File(day.input.into()),
+    }
+}
+
+/// The outcome of solving a single part of a day.
+struct Row {
+    day: u8,
+    part: u8,
+    answer: anyhow::Result<Answer>,
+    verdict: Verdict,
+    elapsed: Duration,
+    /// The SHA-256 of the puzzle input, `None` if it could not be read
+    input_hash: Option<String>,
+}
+
+/// Solves the selected parts of the given day, measures the time of each part.
+///
+/// When the input or its expected answers can't be read every selected part reports the error.
+fn solve(day: &Day, part: Option<u8>, source: &Source) -> Vec<Row> {
+    let input = source
+        .read()
+        .and_then(|input| Expected::for_input(&input).map(|expected| (input, expected)));
+    let input_hash = input
+        .as_ref()
+        .ok()
+        .map(|(input, _)| format!("{:x}", Sha256::digest(input.text.as_bytes())));
+
+    (1..=day.parts)
+        .filter(|&p| part.is_none_or(|part| part == p))
+        .map(|p| {
+            let start = Instant::now();
+            let answer = match &input {
+                Ok((input, _)) => catch_panic(|| (day.solver)(&input.text, p)),
+                Err(err) => Err(anyhow::anyhow!("{:#}", err)),
+            };
+            let elapsed = start.elapsed();
+            let verdict = match (&input, &answer) {
+                (Ok((_, expected)), Ok(answer)) => expected.check(p, answer),
+                _ => Verdict::Unknown,
+            };
+            Row {
+                day: day.day,
+                part: p,
+                answer,
+                verdict,
+                elapsed,
+                input_hash: input_hash.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Runs the solver, a panic is turned into an error with the panic message.
+fn catch_panic<T>(solver: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(solver))
+        .unwrap_or_else(|payload| Err(anyhow::anyhow!("Panicked: {}", panic_message(&*payload))))
+}
+
+/// Returns the message of a panic payload, `panic!` either passes a `&str` or a `String`.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause")
+}
+
+/// Solves all selected days on a thread pool of the given size, `0` uses one thread per core,
+/// a single job or a single day is solved on the current thread.
+///
+/// The rows keep the order of the days, independent of which day finishes first.
+fn solve_days(
+    days: &[&Day],
+    part: Option<u8>,
+    input: Option<&Path>,
+    jobs: usize,
+) -> anyhow::Result<Vec<Row>> {
+    let solve_day = |day: &&Day| solve(day, part, &select_source(day, input));
+    if jobs == 1 || days.len() < 2 {
+        return Ok(days.iter().flat_map(solve_day).collect());
+    }
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .thread_name(|index| format!("aoc-{}", index))
+        .build()?;
+    Ok(pool.install(|| days.par_iter().flat_map_iter(solve_day).collect()))
+}
+
+/// Formats the duration with a fitting unit
+fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_micros();
+    if micros < 1_000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1_000.0)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+/// Prints the summary table, multi line answers continue in the following lines.
+fn print_table(rows: &[Row]) {
+    let answers = rows
+        .iter()
+        .map(|row| match &row.answer {
+            Ok(answer) => answer.to_string().trim_end().to_string(),
+            Err(err) => format!("error: {:#}", err),
+        })
+        .collect::<Vec<_>>();
+
+    let verdicts = rows
+        .iter()
+        .map(|row| row.verdict.to_string())
+        .collect::<Vec<_>>();
+
+    let width = answers
+        .iter()
+        .flat_map(|answer| answer.lines())
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+    let check = verdicts
+        .iter()
+        .map(|verdict| verdict.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Check".len());
+
+    println!(
+        "{:>3} | {:>4} | {:<width$} | {:<check$} | {:>10}",
+        "Day", "Part", "Answer", "Check", "Time"
+    );
+    println!(
+        "{:-<3}-+-{:-<4}-+-{:-<width$}-+-{:-<check$}-+-{:-<10}",
+        "", "", "", "", ""
+    );
+
+    for ((row, answer), verdict) in rows.iter().zip(answers.iter()).zip(verdicts.iter()) {
+        let mut lines = answer.lines();
+        let first = lines.next().unwrap_or_default();
+        println!(
+            "{:>3} | {:>4} | {:<width$} | {:<check$} | {:>10}",
+            row.day,
+            row.part,
+            first,
+            verdict,
+            format_duration(row.elapsed)
+        );
+        for line in lines {
+            println!(
+                "{:>3} | {:>4} | {:<width$} | {:<check$} | {:>10}",
+                "", "", line, "", ""
+            );
+        }
+    }
+
+    let total = rows.iter().map(|row| row.elapsed).sum::<Duration>();
+    println!(
+        "{:-<3}-+-{:-<4}-+-{:-<width$}-+-{:-<check$}-+-{:-<10}",
+        "", "", "", "", ""
+    );
+    println!(
+        "{:>3}   {:>4}   {:<width$}   {:<check$} | {:>10}",
+        "",
+        "",
+        "Total",
+        "",
+        format_duration(total)
+    );
+}
+
+/// A single answer in the JSON output, either the answer or the error is set.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<&'a Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    check: String,
+    elapsed_ns: u64,
+    input_sha256: Option<&'a str>,
+}
+
+/// Prints all answers as JSON array.
+fn print_json(rows: &[Row]) -> anyhow::Result<()> {
+    let records = rows
+        .iter()
+        .map(|row| Record {
+            day: row.day,
+            part: row.part,
+            answer: row.answer.as_ref().ok(),
+            error: row.answer.as_ref().err().map(|err| format!("{:#}", err)),
+            check: row.verdict.to_string(),
+            elapsed_ns: row.elapsed.as_nanos() as u64,
+            input_sha256: row.input_hash.as_deref(),
+        })
+        .collect::<Vec<_>>();
+
+    println!("{}", serde_json::to_string_pretty(&records)?);
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Err(err) = aoc_common::log::init(cli.log.as_deref()) {
+        eprintln!("error: {:#}", err);
+        return ExitCode::FAILURE;
+    }
+
+    match cli.command {
+        Command::Run {
+            day,
+            all,
+            part,
+            input,
+            format,
+            jobs,
+        } => {
+            let start = Instant::now();
+            let rows = match solve_days(&select_days(day, all), part, input.as_deref(), jobs) {
+                Ok(rows) => rows,
+                Err(err) => {
+                    eprintln!("error: {:#}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let elapsed = start.elapsed();
+
+            match format {
+                Format::Table => {
+                    print_table(&rows);
+                    if rows.iter().any(|row| row.day != rows[0].day) {
+                        println!("\nWall time: {}", format_duration(elapsed));
+                    }
+                }
+                Format::Json => {
+                    if let Err(err) = print_json(&rows) {
+                        eprintln!("error: {:#}", err);
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+
+            if rows
+                .iter()
+                .any(|row| row.answer.is_err() || row.verdict.is_failure())
+            {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Bench {
+            day,
+            all,
+            runs,
+            input,
+            output,
+        } => {
+            let reports = select_days(day, all)
+                .into_iter()
+                .map(|day| bench::Report {
+                    day: day.day,
+                    timings: select_source(day, input.as_deref())
+                        .read()
+                        .and_then(|input| (day.bencher)(&input.text, runs)),
+                })
+                .collect::<Vec<_>>();
+
+            bench::print_table(&reports);
+
+            if let Some(path) = output {
+                if let Err(err) = bench::write_csv(&path, &reports) {
+                    eprintln!("error: {:#}", err);
+                    return ExitCode::FAILURE;
+                }
+            }
+            if reports.iter().any(|report| report.timings.is_err()) {
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn panicking(_input: &str, part: u8) -> anyhow::Result<Answer> {
+        match part {
+            1 => Ok(Answer::UInt(1)),
+            _ => panic!("part {} panicked", part),
+        }
+    }
+
+    fn day(day: u8) -> Day {
+        Day {
+            day,
+            input: concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"),
+            parts: 2,
+            solver: panicking,
+            bencher: |_, _| Ok(Vec::new()),
+        }
+    }
+
+    #[test]
+    fn check_panic_is_error() {
+        let err = catch_panic::<()>(|| panic!("boom")).unwrap_err();
+        assert_eq!("Panicked: boom", err.to_string());
+    }
+
+    #[test]
+    fn check_parallel_rows_are_ordered() -> anyhow::Result<()> {
+        let days = (1..=8).map(day).collect::<Vec<_>>();
+        let days = days.iter().collect::<Vec<_>>();
+        let rows = solve_days(&days, None, None, 4)?;
+
+        let order = rows
+            .iter()
+            .map(|row| (row.day, row.part))
+            .collect::<Vec<_>>();
+        let expected = (1..=8)
+            .flat_map(|day| [(day, 1), (day, 2)])
+            .collect::<Vec<_>>();
+        assert_eq!(expected, order);
+        assert!(rows.iter().all(|row| (row.part == 1) == row.answer.is_ok()));
+
+        let sequential = solve_days(&days, None, None, 1)?;
+        assert_eq!(
+            order,
+            sequential
+                .iter()
+                .map(|row| (row.day, row.part))
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+//! Runs a single puzzle day from its binary.
+
+use std::{path::PathBuf, process::ExitCode};
+
+use clap::{Args as ClapArgs, Parser};
+
+use crate::{
+    expected::Expected,
+    input::{Input, Source},
+    log,
+    solution::Solution,
+    ParseError,
+};
+
+/// Command line arguments of a puzzle day.
+#[derive(Debug, Parser)]
+struct Args<O: ClapArgs> {
+    /// Path to the puzzle input file, `-` reads from stdin.
+    ///
+    /// Without a path the input is read from stdin when it is piped in, otherwise the bundled
+    /// input file is used.
+    input: Option<PathBuf>,
+    /// Enables logging to stderr, e.g. `debug` or `day22::cuboid=trace`
+    #[arg(long, value_name = "FILTER")]
+    log: Option<String>,
+    // the options specific to the day
+    #[command(flatten)]
+    options: O,
+}
+
+/// The command line options specific to a puzzle day, e.g. to print a report of the input.
+pub trait Options<S: Solution>: ClapArgs {
+    /// The config to solve both parts with, fails when the options make no valid config.
+    fn config(&self) -> anyhow::Result<S::Config> {
+        Ok(S::Config::default())
+    }
+
+    /// Runs the modes selected by the options on the parsed input before both parts are
+    /// solved, e.g. prints a report, `text` is the raw puzzle input.
+    fn inspect(
+        &self,
+        _text: &str,
+        _input: &S::Input<'_>,
+        _config: &S::Config,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// A puzzle day without specific options.
+#[derive(Debug, ClapArgs)]
+pub struct NoOptions {}
+
+impl<S: Solution> Options<S> for NoOptions {}
+
+/// Loads the puzzle input selected on the command line, solves both parts & checks the
+/// answers against the expected answers of the input.
+///
+/// Errors are printed to stderr, a [`ParseError`] is shown as diagnostic together with the
+/// offending line of the input. Fails when an answer is known to be wrong.
+pub fn run<S: Solution>(bundled: &str) -> ExitCode {
+    run_with::<S, NoOptions>(bundled)
+}
+
+/// Like [`run`], additionally parses the options of the day from the command line.
+pub fn run_with<S: Solution, O: Options<S>>(bundled: &str) -> ExitCode {
+    let args = Args::<O>::parse();
+    let loaded = log::init(args.log.as_deref())
+        .and_then(|()| Source::select(args.input.as_deref(), bundled).read())
+        .and_then(|input| Expected::for_input(&input).map(|expected| (input, expected)));
+    let (input, expected) = match loaded {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            eprintln!("error: {:#}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match solve::<S, O>(&input, &expected, &args.options) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            match err.downcast_ref::<ParseError>() {
+                Some(parse_error) => eprint!("{}", parse_error.report(&input.name, &input.text)),
+                None => eprintln!("error: {:#}", err),
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Solves all parts & prints each answer with its verdict, returns false if any answer is
+/// known to be wrong.
+fn solve<S: Solution, O: Options<S>>(
+    input: &Input,
+    expected: &Expected,
+    options: &O,
+) -> anyhow::Result<bool> {
+    let config = options.config()?;
+    let parsed = S::parse(&input.text, &config)?;
+    options.inspect(&input.text, &parsed, &config)?;
+
+    let mut passed = true;
+    for part in 1..=S::PARTS {
+        let answer = S::part(&parsed, part, &config)?;
+        let verdict = expected.check(part, &answer);
+
+        let answer = answer.to_string();
+        if answer.trim_end().contains('\n') {
+            println!("Part {}: [{}]\n{}", part, verdict, answer.trim_end());
+        } else {
+            println!("Part {}: {} [{}]", part, answer, verdict);
+        }
+        passed &= !verdict.is_failure();
+    }
+    Ok(passed)
+}
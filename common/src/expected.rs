@@ -0,0 +1,226 @@
+//! Expected answers of a puzzle input to catch regressions.
+//!
+//! The expected answers are kept in a TOML file next to the puzzle input, e.g.
+//! `input.answers.toml` for `input.txt`. A part either has the known answer or the bounds of
+//! answers that were already rejected as too high or too low.
+//!
+//! ```toml
+//! [part1]
+//! answer = 4717631
+//!
+//! [part2]
+//! too_low = 4079108237741
+//! ```
+
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use serde::Deserialize;
+
+use crate::{input::Input, solution::Answer};
+
+/// A known answer, either a number or a text.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Value {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(value) => write!(f, "{}", value),
+            Value::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+/// What is known about the answer of a single part.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expectation {
+    /// The correct answer
+    pub answer: Option<Value>,
+    /// The lowest answer that was rejected as too high
+    pub too_high: Option<i64>,
+    /// The highest answer that was rejected as too low
+    pub too_low: Option<i64>,
+}
+
+impl Expectation {
+    /// Checks the answer against the known answer & bounds.
+    pub fn check(&self, answer: &Answer) -> Verdict {
+        let number = match answer {
+            Answer::Int(value) => Some(*value as i128),
+            Answer::UInt(value) => Some(*value as i128),
+            Answer::Text(text) => text.trim().parse::<i128>().ok(),
+            Answer::Image(_) => None,
+        };
+
+        if let Some(number) = number {
+            if let Some(bound) = self.too_high.filter(|&bound| number >= bound as i128) {
+                return Verdict::TooHigh(bound);
+            }
+            if let Some(bound) = self.too_low.filter(|&bound| number <= bound as i128) {
+                return Verdict::TooLow(bound);
+            }
+        }
+
+        match &self.answer {
+            Some(expected) if expected.to_string().trim() == answer.to_string().trim() => {
+                Verdict::Pass
+            }
+            Some(expected) => Verdict::Fail(expected.clone()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// The outcome of checking an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer is the expected answer
+    Pass,
+    /// The answer differs from the expected answer
+    Fail(Value),
+    /// The answer is not below the bound that was rejected as too high
+    TooHigh(i64),
+    /// The answer is not above the bound that was rejected as too low
+    TooLow(i64),
+    /// There is no expected answer to check against
+    Unknown,
+}
+
+impl Verdict {
+    /// Returns true if the answer is known to be wrong.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Fail(_) | Verdict::TooHigh(_) | Verdict::TooLow(_)
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            // multi line answers don't fit into a single line report
+            Verdict::Fail(Value::Text(text)) if text.trim().contains('\n') => write!(f, "FAIL"),
+            Verdict::Fail(expected) => write!(f, "FAIL, expected {}", expected),
+            Verdict::TooHigh(bound) => write!(f, "FAIL, too high (>= {})", bound),
+            Verdict::TooLow(bound) => write!(f, "FAIL, too low (<= {})", bound),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// The expected answers of both parts of a puzzle input.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    part1: Option<Expectation>,
+    part2: Option<Expectation>,
+}
+
+impl Expected {
+    /// Returns the path of the expected answers file of the puzzle input file.
+    pub fn path_for(input: &Path) -> PathBuf {
+        input.with_extension("answers.toml")
+    }
+
+    /// Loads the expected answers of the puzzle input, nothing is expected when the input has
+    /// no expected answers file, e.g. when it's read from stdin.
+    pub fn for_input(input: &Input) -> anyhow::Result<Self> {
+        let Some(path) = &input.path else {
+            return Ok(Self::default());
+        };
+
+        let path = Self::path_for(path);
+        match fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text)
+                .with_context(|| format!("Failed to parse expected answers '{}'", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err)
+                .with_context(|| format!("Failed to read expected answers '{}'", path.display())),
+        }
+    }
+
+    /// Parses the expected answers from TOML.
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        Ok(toml::from_str(text)?)
+    }
+
+    /// Checks the answer of the given part.
+    pub fn check(&self, part: u8, answer: &Answer) -> Verdict {
+        let expectation = match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        };
+        expectation.map_or(Verdict::Unknown, |expectation| expectation.check(answer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPECTED: &str = r#"
+        [part1]
+        answer = 4717631
+
+        [part2]
+        too_high = 200
+        too_low = 146
+    "#;
+
+    #[test]
+    fn check_parse() -> anyhow::Result<()> {
+        let expected = Expected::parse(EXPECTED)?;
+        assert_eq!(
+            Some(Expectation {
+                answer: Some(Value::Number(4717631)),
+                too_high: None,
+                too_low: None,
+            }),
+            expected.part1
+        );
+        assert!(Expected::parse("[part1]\nanswr = 1").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_verdicts() -> anyhow::Result<()> {
+        let expected = Expected::parse(EXPECTED)?;
+        assert_eq!(Verdict::Pass, expected.check(1, &Answer::UInt(4717631)));
+        assert_eq!(
+            Verdict::Fail(Value::Number(4717631)),
+            expected.check(1, &Answer::Int(12))
+        );
+        assert_eq!(Verdict::TooLow(146), expected.check(2, &Answer::Int(146)));
+        assert_eq!(Verdict::TooHigh(200), expected.check(2, &Answer::Int(250)));
+        assert_eq!(Verdict::Unknown, expected.check(2, &Answer::Int(147)));
+        assert_eq!(
+            Verdict::Unknown,
+            Expected::default().check(1, &Answer::Int(1))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_text_answers() -> anyhow::Result<()> {
+        let expected = Expected::parse("[part1]\nanswer = \"\"\"\n#..#\n.##.\n\"\"\"")?;
+        assert_eq!(
+            Verdict::Pass,
+            expected.check(1, &Answer::from("#..#\n.##.\n"))
+        );
+        assert_eq!("FAIL", expected.check(1, &Answer::from("#")).to_string());
+        Ok(())
+    }
+}
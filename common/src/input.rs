@@ -0,0 +1,131 @@
+//! Loads the puzzle input at runtime.
+//!
+//! The input is either read from a file given on the command line, from stdin or from the
+//! input file bundled with each day.
+
+use std::{
+    fs,
+    io::{self, IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+/// The puzzle input together with the name of its source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    /// The file path or `<stdin>`
+    pub name: String,
+    /// The path of the input file, `None` when read from stdin
+    pub path: Option<PathBuf>,
+    /// The content of the puzzle input
+    pub text: String,
+}
+
+/// The source to read the puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// Read the input from a file
+    File(PathBuf),
+    /// Read the input from stdin, fails when stdin is empty
+    Stdin,
+    /// Read the input from stdin, falls back to the bundled file when nothing is piped in
+    StdinOrFile(PathBuf),
+}
+
+impl Source {
+    /// Selects the source from an optional path argument, `-` selects stdin.
+    ///
+    /// Without a path stdin is selected when it's not a terminal, otherwise the bundled file.
+    /// Only without a path an empty stdin falls back to the bundled file.
+    pub fn select(path: Option<&Path>, bundled: &str) -> Self {
+        match path {
+            Some(path) if path == Path::new("-") => Source::Stdin,
+            Some(path) => Source::File(path.into()),
+            None if !io::stdin().is_terminal() => Source::StdinOrFile(bundled.into()),
+            None => Source::File(bundled.into()),
+        }
+    }
+
+    /// Reads the full input from this source.
+    pub fn read(&self) -> anyhow::Result<Input> {
+        match self {
+            Source::File(path) => read_file(path),
+            Source::Stdin => {
+                let input = read_stdin()?;
+                if input.text.is_empty() {
+                    anyhow::bail!("No input on stdin");
+                }
+                Ok(input)
+            }
+            Source::StdinOrFile(bundled) => {
+                let input = read_stdin()?;
+                // Nothing was piped in, e.g. stdin is closed or redirected from `/dev/null`
+                if input.text.is_empty() {
+                    read_file(bundled)
+                } else {
+                    Ok(input)
+                }
+            }
+        }
+    }
+}
+
+/// Reads all of stdin.
+fn read_stdin() -> anyhow::Result<Input> {
+    let mut text = String::new();
+    io::stdin()
+        .read_to_string(&mut text)
+        .context("Failed to read input from stdin")?;
+    Ok(Input {
+        name: "<stdin>".to_string(),
+        path: None,
+        text,
+    })
+}
+
+/// Reads the input file, the error names the file that could not be read.
+fn read_file(path: &Path) -> anyhow::Result<Input> {
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read input file '{}'", path.display()))?;
+    Ok(Input {
+        name: path.display().to_string(),
+        path: Some(path.into()),
+        text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_select_source() {
+        assert_eq!(
+            Source::File("input.txt".into()),
+            Source::select(Some(Path::new("input.txt")), "bundled.txt"),
+        );
+        assert_eq!(
+            Source::Stdin,
+            Source::select(Some(Path::new("-")), "bundled.txt")
+        );
+    }
+
+    #[test]
+    fn check_missing_file() {
+        let error = Source::File("does/not/exist.txt".into())
+            .read()
+            .expect_err("Reading a missing file must fail");
+        assert_eq!(
+            "Failed to read input file 'does/not/exist.txt'",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn check_read_file() -> anyhow::Result<()> {
+        let input = Source::File(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").into());
+        assert!(input.read()?.text.contains("aoc-common"));
+        Ok(())
+    }
+}
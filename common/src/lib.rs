@@ -0,0 +1,11 @@
+//! Shared functionality of all puzzle days.
+
+mod driver;
+pub mod expected;
+pub mod input;
+pub mod log;
+pub mod parse;
+pub mod solution;
+
+pub use driver::{run, run_with, NoOptions, Options};
+pub use parse::ParseError;
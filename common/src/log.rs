@@ -0,0 +1,60 @@
+//! Leveled logging of the puzzle days, silent unless enabled on the command line.
+//!
+//! The days log with [`tracing`](https://docs.rs/tracing), the filter selects the days &
+//! subsystems to log, e.g. `day22=debug,day22::cuboid=trace`. A `dayNN` target is short for
+//! the crate of the day, `aoc_2022_day_NN`. The log is written to stderr to keep the answers on
+//! stdout untouched.
+
+use std::io::{self, IsTerminal};
+
+use anyhow::{anyhow, Context};
+use tracing_subscriber::EnvFilter;
+
+/// Installs the logger for the given filter, nothing is logged without a filter.
+pub fn init(filter: Option<&str>) -> anyhow::Result<()> {
+    let Some(filter) = filter else {
+        return Ok(());
+    };
+
+    let filter = EnvFilter::try_new(expand(filter))
+        .with_context(|| format!("Invalid log filter '{}'", filter))?;
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .try_init()
+        .map_err(|err| anyhow!("Failed to install logger: {}", err))
+}
+
+/// Expands the `dayNN` shorthand of each directive to the crate name of the day.
+fn expand(filter: &str) -> String {
+    filter
+        .split(',')
+        .map(|directive| {
+            let digits = directive.strip_prefix("day").map(|rest| rest.as_bytes());
+            match digits {
+                Some([a, b, ..]) if a.is_ascii_digit() && b.is_ascii_digit() => {
+                    format!("aoc_2022_day_{}", &directive[3..])
+                }
+                _ => directive.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_expand() {
+        assert_eq!("debug", expand("debug"));
+        assert_eq!(
+            "aoc_2022_day_22=debug,aoc_2022_day_22::cuboid=trace",
+            expand("day22=debug,day22::cuboid=trace")
+        );
+        assert_eq!("days=info", expand("days=info"));
+    }
+}
@@ -0,0 +1,169 @@
+//! Errors of malformed puzzle input.
+//!
+//! A [`ParseError`] points to the offending text in the puzzle input by line & column, it's
+//! rendered as a diagnostic together with the line of the input.
+
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// The puzzle input could not be parsed at the given location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the offending text, starts at `1`
+    pub line: usize,
+    /// The column of the offending text in characters, starts at `1`
+    pub column: usize,
+    /// The offending text
+    pub text: String,
+    /// Describes what went wrong
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates the error for the offending `text`, a sub slice of the puzzle `input`.
+    ///
+    /// The location is derived from the position of `text` within `input`, otherwise the first
+    /// occurrence of `text` is used.
+    pub fn at(input: &str, text: &str, message: impl Into<String>) -> Self {
+        let (line, column) = location(input, offset_of(input, text));
+        Self::new(line, column, text, message)
+    }
+
+    /// Creates the error for the byte `offset` in the puzzle `input`, e.g. of a parser
+    /// combinator that stopped at this position.
+    ///
+    /// The offending text is the word starting at the offset.
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+        let text = input[offset..]
+            .split(char::is_whitespace)
+            .next()
+            .unwrap_or_default();
+        Self::at(input, text, message)
+    }
+
+    /// Converts the error of a [`peg`] parser that parsed `part`, a sub slice of the puzzle
+    /// `input`.
+    pub fn peg(input: &str, part: &str, error: peg::error::ParseError<peg::str::LineCol>) -> Self {
+        let offset = offset_of(input, part) + error.location.offset;
+        Self::at_offset(input, offset, format!("Expected {}", error.expected))
+    }
+
+    /// Renders the error as a diagnostic, shows the offending line of the input.
+    ///
+    /// ```text
+    /// error: Unknown hand: 'Q'
+    ///  --> input.txt:3:1
+    ///   |
+    /// 3 | Q Y
+    ///   | ^
+    /// ```
+    pub fn report(&self, name: &str, input: &str) -> String {
+        let source = input.lines().nth(self.line - 1).unwrap_or_default();
+        let gutter = " ".repeat(self.line.to_string().len());
+        let marker = format!(
+            "{}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.text.chars().count().max(1))
+        );
+
+        format!(
+            "error: {}: '{}'\n{gutter}--> {}:{}:{}\n{gutter} |\n{} | {}\n{gutter} | {}\n",
+            self.message, self.text, name, self.line, self.column, self.line, source, marker,
+        )
+    }
+}
+
+/// Returns the line & column of the byte offset, both start at `1`.
+fn location(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: '{}' at line {}, column {}",
+            self.message, self.text, self.line, self.column
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Returns the byte offset of `part`, a sub slice of the puzzle `input`.
+///
+/// Used to locate errors of parsers that only see a single line of the input.
+pub fn offset_of(input: &str, part: &str) -> usize {
+    (part.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|&offset| offset + part.len() <= input.len())
+        .or_else(|| input.find(part))
+        .unwrap_or_default()
+}
+
+/// Parses the number `text`, a sub slice of the puzzle `input`.
+pub fn number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse::<T>()
+        .map_err(|_| ParseError::at(input, text, "Invalid number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "A Y\nB X\nC Q\n";
+
+    #[test]
+    fn check_location() {
+        let text = &INPUT[10..11];
+        assert_eq!(
+            ParseError::new(3, 3, "Q", "Unknown hand"),
+            ParseError::at(INPUT, text, "Unknown hand")
+        );
+        assert_eq!(
+            ParseError::new(2, 1, "B", "Unknown hand"),
+            ParseError::at_offset(INPUT, 4, "Unknown hand")
+        );
+    }
+
+    #[test]
+    fn check_report() {
+        let error = ParseError::at(INPUT, &INPUT[8..9], "Unknown hand");
+        assert_eq!(
+            "error: Unknown hand: 'C'\n --> input.txt:3:1\n  |\n3 | C Q\n  | ^\n",
+            error.report("input.txt", INPUT)
+        );
+    }
+
+    #[test]
+    fn check_number() {
+        let input = "12 x4";
+        assert_eq!(3, offset_of(input, &input[3..]));
+        assert_eq!(Ok(12), number::<u32>(input, &input[0..2]));
+        assert_eq!(
+            Err(ParseError::new(1, 4, "x4", "Invalid number")),
+            number::<u32>(input, &input[3..])
+        );
+    }
+}
@@ -0,0 +1,192 @@
+//! The common interface of all puzzle days.
+//!
+//! Each day parses its puzzle input once, both parts then compute their [`Answer`] from the
+//! parsed input. Puzzle specific parameters, e.g. the row to check in day 15, are given by a
+//! typed config.
+
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+
+/// The answer to a single part of a puzzle.
+///
+/// Serializes numbers as JSON numbers, texts as strings & images as object of their rows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    /// A signed number
+    Int(i64),
+    /// An unsigned number
+    UInt(u64),
+    /// A text answer, e.g. a code of letters
+    Text(String),
+    /// An image of pixels, e.g. letters drawn on a screen
+    Image(Image),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::UInt(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Image(image) => write!(f, "{}", image),
+        }
+    }
+}
+
+/// A rectangular image of pixels, each row is a line of characters.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub rows: Vec<String>,
+}
+
+impl Image {
+    /// Creates the image from text, each line is a row of pixels.
+    pub fn from_text(text: &str) -> Self {
+        let rows = text.lines().map(String::from).collect::<Vec<_>>();
+        Self {
+            width: rows
+                .iter()
+                .map(|row| row.chars().count())
+                .max()
+                .unwrap_or(0),
+            height: rows.len(),
+            rows,
+        }
+    }
+}
+
+impl Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.rows {
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Image> for Answer {
+    fn from(image: Image) -> Self {
+        Answer::Image(image)
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident, $target:ty, $($source:ty),+) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+impl_from!(Int, i64, i32, i64);
+impl_from!(UInt, u64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// A puzzle day, parses the puzzle input and solves both parts.
+pub trait Solution {
+    /// The day of the puzzle, starts at `1`
+    const DAY: u8;
+    /// The title of the puzzle
+    const TITLE: &'static str;
+    /// The number of parts, the last day only has a single part.
+    const PARTS: u8 = 2;
+
+    /// The parsed puzzle input, may borrow from the raw input.
+    type Input<'a>;
+    /// Puzzle specific parameters, the default fits the actual puzzle input.
+    type Config: Default;
+
+    /// Parses the raw puzzle input.
+    fn parse<'a>(input: &'a str, config: &Self::Config) -> anyhow::Result<Self::Input<'a>>;
+
+    /// Solves the first part.
+    fn part1(input: &Self::Input<'_>, config: &Self::Config) -> anyhow::Result<Answer>;
+
+    /// Solves the second part, fails for days with a single part only.
+    fn part2(_input: &Self::Input<'_>, _config: &Self::Config) -> anyhow::Result<Answer> {
+        anyhow::bail!("Day {} has no part 2", Self::DAY)
+    }
+
+    /// Solves the given part of the parsed input.
+    fn part(input: &Self::Input<'_>, part: u8, config: &Self::Config) -> anyhow::Result<Answer> {
+        match part {
+            1 => Self::part1(input, config),
+            2 => Self::part2(input, config),
+            _ => anyhow::bail!("Day {} has no part {}", Self::DAY, part),
+        }
+    }
+
+    /// Parses the raw input and solves the given part.
+    fn solve(input: &str, part: u8, config: &Self::Config) -> anyhow::Result<Answer> {
+        let input = Self::parse(input, config)?;
+        Self::part(&input, part, config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sums all numbers, counts the numbers in part 2.
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Sum";
+
+        type Input<'a> = Vec<&'a str>;
+        type Config = ();
+
+        fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Vec<&'a str>> {
+            Ok(input.split_whitespace().collect())
+        }
+
+        fn part1(input: &Vec<&str>, _config: &()) -> anyhow::Result<Answer> {
+            let numbers = input
+                .iter()
+                .map(|n| n.parse::<i64>())
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(numbers.iter().sum::<i64>().into())
+        }
+    }
+
+    #[test]
+    fn check_solve() {
+        assert_eq!(Answer::Int(6), Sum::solve("1 2 3", 1, &()).unwrap());
+        assert!(Sum::solve("1 2 3", 2, &()).is_err());
+        assert!(Sum::solve("1 a 3", 1, &()).is_err());
+    }
+
+    #[test]
+    fn check_display_answer() {
+        assert_eq!("-4", Answer::from(-4i32).to_string());
+        assert_eq!("42", Answer::from(42usize).to_string());
+        assert_eq!("CMZ", Answer::from("CMZ").to_string());
+    }
+
+    #[test]
+    fn check_image() {
+        let image = Image::from_text("#..#\n.##.\n");
+        assert_eq!((4, 2), (image.width, image.height));
+        assert_eq!("#..#\n.##.\n", Answer::from(image).to_string());
+    }
+}
@@ -0,0 +1,612 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    fmt::{self, Display},
+    io::BufRead,
+    num::IntErrorKind,
+};
+
+use anyhow::anyhow;
+use aoc_common::solution::{Answer, Solution};
+use aoc_common::ParseError;
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+/// A line of the puzzle input that is no valid number of calories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejected {
+    /// The elf the line belongs to, starts at `0`
+    pub group: usize,
+    /// The line in the puzzle input, starts at `1`
+    pub line: usize,
+    /// The rejected text
+    pub text: String,
+    /// Why the line was rejected
+    pub reason: &'static str,
+}
+
+impl Display for Rejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, elf {}: {}: '{}'",
+            self.line, self.group, self.reason, self.text
+        )
+    }
+}
+
+/// The calories of all food items grouped by elf, together with the rejected lines.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Inventory {
+    pub groups: Vec<Vec<u64>>,
+    /// The skipped lines, always empty in strict mode
+    pub rejected: Vec<Rejected>,
+}
+
+/// Parses the calories of a single food item, the total of its elf must not overflow.
+fn parse_item(text: &str, total: u64) -> Result<u64, &'static str> {
+    let calories = text.parse::<u64>().map_err(|err| match err.kind() {
+        IntErrorKind::PosOverflow => "Number too large",
+        _ => "Invalid number",
+    })?;
+    total
+        .checked_add(calories)
+        .map(|_| calories)
+        .ok_or("Calories total overflows")
+}
+
+/// Parses the input & groups successive calories to one elf, blank lines separate the elves.
+///
+/// In strict mode the first rejected line is an error, otherwise all rejected lines are
+/// skipped & recorded.
+pub fn parse_inventory(input: &str, strict: bool) -> Result<Inventory, ParseError> {
+    let mut inventory = Inventory::default();
+    let mut group: Option<(Vec<u64>, u64)> = None;
+
+    for (index, line) in input.lines().enumerate() {
+        let text = line.trim();
+        if text.is_empty() {
+            inventory
+                .groups
+                .extend(group.take().map(|(items, _)| items));
+            continue;
+        }
+
+        let (items, total) = group.get_or_insert_with(Default::default);
+        match parse_item(text, *total) {
+            Ok(calories) => {
+                items.push(calories);
+                *total += calories;
+            }
+            Err(reason) if strict => return Err(ParseError::at(input, text, reason)),
+            Err(reason) => inventory.rejected.push(Rejected {
+                group: inventory.groups.len(),
+                line: index + 1,
+                text: text.to_string(),
+                reason,
+            }),
+        }
+    }
+
+    inventory.groups.extend(group.map(|(items, _)| items));
+    Ok(inventory)
+}
+
+/// Parse input & group list of successive calories to one elf, fails on the first line that
+/// is no number.
+pub fn parse(input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    Ok(parse_inventory(input, true)?.groups)
+}
+
+/// An elf with the calories of all food items it carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    /// The position of the elf in the puzzle input, starts at `0`
+    pub index: usize,
+    /// The number of food items
+    pub items: usize,
+    /// The summed calories of all food items
+    pub calories: u64,
+}
+
+impl Ord for Elf {
+    /// Elves with more calories rank higher, on a tie the elf that comes first in the input.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.calories
+            .cmp(&other.calories)
+            .then_with(|| other.index.cmp(&self.index))
+            .then_with(|| self.items.cmp(&other.items))
+    }
+}
+
+impl PartialOrd for Elf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Returns all elves of the parsed input in input order, fails when the calories of one elf
+/// overflow.
+pub fn elves(groups: &[Vec<u64>]) -> anyhow::Result<Vec<Elf>> {
+    groups
+        .iter()
+        .enumerate()
+        .map(|(index, items)| {
+            let calories = items
+                .iter()
+                .try_fold(0u64, |sum, &calories| sum.checked_add(calories))
+                .ok_or_else(|| anyhow!("Calories sum of elf {} overflows", index + 1))?;
+            Ok(Elf {
+                index,
+                items: items.len(),
+                calories,
+            })
+        })
+        .collect()
+}
+
+/// Returns the `k` elves with the most calories, the highest ranked elf first.
+///
+/// Only a heap of the `k` best elves seen so far is kept, ties are ranked by the position of
+/// the elves in the input.
+pub fn top_elves(elves: impl IntoIterator<Item = Elf>, k: usize) -> Vec<Elf> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for elf in elves {
+        heap.push(Reverse(elf));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(elf)| elf)
+        .collect()
+}
+
+/// Returns the summed calories of the `k` elves with the most calories, fails when the sum
+/// overflows.
+pub fn top_calories(groups: &[Vec<u64>], k: usize) -> anyhow::Result<u64> {
+    top_elves(elves(groups)?, k)
+        .iter()
+        .try_fold(0u64, |sum, elf| sum.checked_add(elf.calories))
+        .ok_or_else(|| anyhow!("Calories sum of the top {} elves overflows", k))
+}
+
+/// Return the highest calories sum of one elf
+pub fn part1(elves: &[Vec<u64>]) -> anyhow::Result<u64> {
+    top_calories(elves, 1)
+}
+
+/// Return the calories sums of th top three elves
+pub fn part2(elves: &[Vec<u64>]) -> anyhow::Result<u64> {
+    top_calories(elves, 3)
+}
+
+/// A range of calories sums in the histogram of the [`Report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    /// The lowest calories sum of the bucket
+    pub start: u64,
+    /// The highest calories sum of the bucket, inclusive
+    pub end: u64,
+    /// The number of elves in this range
+    pub elves: usize,
+}
+
+/// Statistics over the calories sums of all elves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub elves: usize,
+    pub mean: f64,
+    pub median: f64,
+    /// The population standard deviation
+    pub std_dev: f64,
+    /// Buckets of equal width from the lowest to the highest calories sum
+    pub histogram: Vec<Bucket>,
+}
+
+/// Computes the statistics of the calories sums with a histogram of at most `buckets` buckets,
+/// fails when the calories of one elf overflow.
+pub fn report(groups: &[Vec<u64>], buckets: usize) -> anyhow::Result<Report> {
+    let mut sums = elves(groups)?
+        .into_iter()
+        .map(|elf| elf.calories)
+        .collect::<Vec<_>>();
+    sums.sort_unstable();
+
+    let (Some(&min), Some(&max)) = (sums.first(), sums.last()) else {
+        return Ok(Report {
+            elves: 0,
+            mean: 0.0,
+            median: 0.0,
+            std_dev: 0.0,
+            histogram: Vec::new(),
+        });
+    };
+
+    // the sums of all elves may exceed `u64`, the statistics are computed with `u128`
+    let len = sums.len();
+    let mean = sums.iter().map(|&sum| sum as u128).sum::<u128>() as f64 / len as f64;
+    let median = match len % 2 {
+        1 => sums[len / 2] as f64,
+        _ => (sums[len / 2 - 1] as u128 + sums[len / 2] as u128) as f64 / 2.0,
+    };
+    let variance = sums
+        .iter()
+        .map(|&sum| (sum as f64 - mean).powi(2))
+        .sum::<f64>()
+        / len as f64;
+
+    let width = ((max - min) as u128 + 1).div_ceil(buckets.max(1) as u128);
+    let mut histogram = (0..=(max - min) as u128 / width)
+        .map(|bucket| {
+            let start = min as u128 + bucket * width;
+            Bucket {
+                start: start as u64,
+                end: (start + width - 1).min(max as u128) as u64,
+                elves: 0,
+            }
+        })
+        .collect::<Vec<_>>();
+    for sum in sums {
+        histogram[((sum - min) as u128 / width) as usize].elves += 1;
+    }
+
+    Ok(Report {
+        elves: len,
+        mean,
+        median,
+        std_dev: variance.sqrt(),
+        histogram,
+    })
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const BAR: usize = 40;
+
+        writeln!(f, "Elves:   {}", self.elves)?;
+        writeln!(f, "Mean:    {:.1}", self.mean)?;
+        writeln!(f, "Median:  {:.1}", self.median)?;
+        writeln!(f, "Std dev: {:.1}", self.std_dev)?;
+
+        let most = self.histogram.iter().map(|bucket| bucket.elves).max();
+        for bucket in &self.histogram {
+            let bar = bucket.elves * BAR / most.unwrap_or(1).max(1);
+            writeln!(
+                f,
+                "{:>6} - {:>6} | {:<BAR$} {}",
+                bucket.start,
+                bucket.end,
+                "#".repeat(bar),
+                bucket.elves
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Streams the calories sum of each elf from a reader, one group of lines at a time.
+///
+/// Only the running sum of the current group is kept, which allows to process calorie logs
+/// that don't fit into memory. Lines may end with `\r\n`, any number of blank lines separate
+/// the groups.
+pub struct CalorieSums<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+}
+
+impl<R: BufRead> CalorieSums<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            line_number: 0,
+        }
+    }
+
+    /// Reads the lines of the next group, returns `None` at the end of the input.
+    fn next_sum(&mut self) -> anyhow::Result<Option<u64>> {
+        let mut sum = None;
+        loop {
+            self.line.clear();
+            if self.reader.read_line(&mut self.line)? == 0 {
+                return Ok(sum);
+            }
+            self.line_number += 1;
+
+            let text = self.line.trim();
+            if text.is_empty() {
+                if sum.is_some() {
+                    return Ok(sum);
+                }
+                continue;
+            }
+
+            let total = sum.unwrap_or(0);
+            let calories = parse_item(text, total).map_err(|reason| {
+                let indent = self.line.len() - self.line.trim_start().len();
+                let column = self.line[..indent].chars().count() + 1;
+                ParseError::new(self.line_number, column, text, reason)
+            })?;
+            sum = Some(total + calories);
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for CalorieSums<R> {
+    type Item = anyhow::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_sum().transpose()
+    }
+}
+
+/// Returns the three highest calories sums of the streamed elves, biggest sum first.
+///
+/// The first sum is the answer of [`part1`], the total of all three the answer of [`part2`].
+pub fn top_three<R: BufRead>(reader: R) -> anyhow::Result<[u64; 3]> {
+    let mut top = [0; 3];
+    for sum in CalorieSums::new(reader) {
+        let sum = sum?;
+        if let Some(index) = top.iter().position(|&calories| sum > calories) {
+            top[index..].rotate_right(1);
+            top[index] = sum;
+        }
+    }
+    Ok(top)
+}
+
+/// The number of elves to sum in part 2 & how to handle malformed lines.
+pub struct Config {
+    /// The number of elves with the most calories
+    pub top: usize,
+    /// Fails on the first rejected line, otherwise rejected lines are skipped & kept in the
+    /// [`Inventory`]. Strict by default, only the day binary skips & reports rejected lines.
+    pub strict: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            top: 3,
+            strict: true,
+        }
+    }
+}
+
+/// Day 01: Calorie Counting
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    type Input<'a> = Inventory;
+    type Config = Config;
+
+    fn parse<'a>(input: &'a str, config: &Config) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_inventory(input, config.strict)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Config) -> anyhow::Result<Answer> {
+        Ok(part1(&input.groups)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>, config: &Config) -> anyhow::Result<Answer> {
+        Ok(top_calories(&input.groups, config.top)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{solution::Solution, ParseError};
+
+    use crate::{
+        elves, parse, parse_inventory, part1, part2, report, top_elves, top_three, Bucket,
+        CalorieSums, Config, Day01, Elf, Rejected,
+    };
+
+    const INPUT: &str = r#"
+        1000
+        2000
+        3000
+
+        4000
+
+        5000
+        6000
+
+        7000
+        8000
+        9000
+
+        10000
+    "#;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(24_000, part1(&parse(INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(45_000, part2(&parse(INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_rejected_lines() {
+        let input = "1000\n1x00\n\n99999999999999999999\n\n3000\r\n";
+        let inventory = parse_inventory(input, false).unwrap();
+        assert_eq!(vec![vec![1000], vec![], vec![3000]], inventory.groups);
+        assert_eq!(
+            vec![
+                Rejected {
+                    group: 0,
+                    line: 2,
+                    text: "1x00".to_string(),
+                    reason: "Invalid number"
+                },
+                Rejected {
+                    group: 1,
+                    line: 4,
+                    text: "99999999999999999999".to_string(),
+                    reason: "Number too large"
+                }
+            ],
+            inventory.rejected
+        );
+        assert_eq!(
+            "line 2, elf 0: Invalid number: '1x00'",
+            inventory.rejected[0].to_string()
+        );
+        assert_eq!(
+            Err(ParseError::new(2, 1, "1x00", "Invalid number")),
+            parse_inventory(input, true)
+        );
+        // rejected lines fail by default
+        assert!(Day01::parse(input, &Config::default()).is_err());
+        let config = Config {
+            strict: false,
+            ..Config::default()
+        };
+        assert_eq!(inventory, Day01::parse(input, &config).unwrap());
+    }
+
+    #[test]
+    fn test_total_overflow() {
+        let input = format!("{}\n1\n\n5", u64::MAX);
+        assert_eq!(
+            Err(ParseError::new(2, 1, "1", "Calories total overflows")),
+            parse(&input)
+        );
+        let inventory = parse_inventory(&input, false).unwrap();
+        assert_eq!(vec![vec![u64::MAX], vec![5]], inventory.groups);
+        assert!(top_three(input.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_sum_overflow() {
+        let half = u64::MAX / 2 + 1;
+        let groups = vec![vec![half], vec![half], vec![1]];
+        assert_eq!(half, part1(&groups).unwrap());
+        assert!(part2(&groups).is_err());
+
+        let overflowing = [vec![u64::MAX, 1]];
+        assert!(part1(&overflowing).is_err());
+        assert!(elves(&overflowing).is_err());
+        assert!(report(&overflowing, 1).is_err());
+
+        let stats = report(&groups, 2).unwrap();
+        assert_eq!(half as f64, stats.median);
+        assert_eq!((2 * half as u128 + 1) as f64 / 3.0, stats.mean);
+        assert_eq!(
+            vec![1, 2],
+            stats
+                .histogram
+                .iter()
+                .map(|bucket| bucket.elves)
+                .collect::<Vec<_>>()
+        );
+
+        let stats = report(&[vec![0], vec![u64::MAX]], 1).unwrap();
+        assert_eq!(
+            vec![Bucket {
+                start: 0,
+                end: u64::MAX,
+                elves: 2
+            }],
+            stats.histogram
+        );
+    }
+
+    #[test]
+    fn test_top_elves() {
+        let groups = parse(INPUT).unwrap();
+        let top = top_elves(elves(&groups).unwrap(), 2);
+        assert_eq!(
+            vec![
+                Elf {
+                    index: 3,
+                    items: 3,
+                    calories: 24_000
+                },
+                Elf {
+                    index: 2,
+                    items: 2,
+                    calories: 11_000
+                }
+            ],
+            top
+        );
+        assert_eq!(5, top_elves(elves(&groups).unwrap(), 10).len());
+        assert!(top_elves(elves(&groups).unwrap(), 0).is_empty());
+    }
+
+    #[test]
+    fn test_top_elves_ties() {
+        let groups = parse("300\n\n100\n200\n\n300\n\n50").unwrap();
+        let indices = top_elves(elves(&groups).unwrap(), 3)
+            .iter()
+            .map(|elf| elf.index)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![0, 1, 2], indices);
+    }
+
+    #[test]
+    fn test_report() {
+        let report = report(&parse(INPUT).unwrap(), 3).unwrap();
+        assert_eq!(5, report.elves);
+        assert_eq!(11_000.0, report.mean);
+        assert_eq!(10_000.0, report.median);
+        assert!((report.std_dev - 6_985.699).abs() < 0.001);
+        assert_eq!(
+            vec![
+                Bucket {
+                    start: 4_000,
+                    end: 10_666,
+                    elves: 3
+                },
+                Bucket {
+                    start: 10_667,
+                    end: 17_333,
+                    elves: 1
+                },
+                Bucket {
+                    start: 17_334,
+                    end: 24_000,
+                    elves: 1
+                },
+            ],
+            report.histogram
+        );
+    }
+
+    #[test]
+    fn test_streaming() -> anyhow::Result<()> {
+        let elves = parse(INPUT)?;
+        let top = top_three(INPUT.as_bytes())?;
+        assert_eq!(part1(&elves)?, top[0]);
+        assert_eq!(part2(&elves)?, top.iter().sum());
+        Ok(())
+    }
+
+    #[test]
+    fn test_streaming_line_endings() -> anyhow::Result<()> {
+        let input = "1000\r\n2000\r\n\r\n\r\n4000\r\n\r\n\r\n";
+        let sums = CalorieSums::new(input.as_bytes()).collect::<anyhow::Result<Vec<_>>>()?;
+        assert_eq!(vec![3000, 4000], sums);
+        Ok(())
+    }
+
+    #[test]
+    fn test_streaming_error() {
+        let err = top_three("1000\n\n  20x0\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            Some(&ParseError::new(3, 3, "20x0", "Invalid number")),
+            err.downcast_ref::<ParseError>()
+        );
+    }
+}
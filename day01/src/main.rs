@@ -1,68 +1,42 @@
-use itertools::Itertools;
-
-/// Parse input & group list of successive calories to one elf.
-fn parse(input: &str) -> Vec<Vec<u32>> {
-    input
-        .split("\n\n")
-        .map(|group| {
-            group
-                .lines()
-                .map(str::trim)
-                .filter_map(|s| s.parse::<u32>().ok())
-                .collect_vec()
-        })
-        .collect_vec()
-}
-
-/// Returns the summed calories for each elf, sorted by biggest sum first
-fn sorted_sums(elves: &[Vec<u32>]) -> impl Iterator<Item = u32> + '_ {
-    elves.iter().map(|elf| elf.iter().sum()).sorted().rev()
-}
-
-/// Return the highest calories sum of one elf
-fn part1(elves: &[Vec<u32>]) -> u32 {
-    sorted_sums(elves).next().unwrap()
-}
-
-/// Return the calories sums of th top three elves
-fn part2(elves: &[Vec<u32>]) -> u32 {
-    sorted_sums(elves).take(3).sum()
-}
-
-fn main() {
-    let calories = parse(include_str!("input.txt"));
-    println!("Max calories: {}", part1(&calories));
-    println!("Top three calories: {}", part2(&calories));
+use std::process::ExitCode;
+
+use aoc_2022_day_01::{report, Config, Day01, Inventory, INPUT_PATH};
+use aoc_common::Options;
+
+/// The options of day 01.
+#[derive(Debug, clap::Args)]
+struct Day01Options {
+    /// Fails on the first line that is no valid number of calories, by default such lines are
+    /// skipped & listed as warnings
+    #[arg(long)]
+    strict: bool,
+    /// Prints statistics & a histogram of the calories sums of all elves
+    #[arg(long)]
+    report: bool,
+    /// The number of buckets of the histogram
+    #[arg(long, default_value_t = 10, requires = "report")]
+    buckets: usize,
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::{parse, part1, part2};
-
-    const INPUT: &str = r#"
-        1000
-        2000
-        3000
-
-        4000
-
-        5000
-        6000
-
-        7000
-        8000
-        9000
-
-        10000
-    "#;
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(24_000, part1(&parse(INPUT)));
+impl Options<Day01> for Day01Options {
+    fn config(&self) -> anyhow::Result<Config> {
+        Ok(Config {
+            strict: self.strict,
+            ..Config::default()
+        })
     }
 
-    #[test]
-    fn test_part2() {
-        assert_eq!(45_000, part2(&parse(INPUT)));
+    fn inspect(&self, _text: &str, input: &Inventory, _config: &Config) -> anyhow::Result<()> {
+        for rejected in &input.rejected {
+            eprintln!("warning: rejected {}", rejected);
+        }
+        if self.report {
+            println!("{}", report(&input.groups, self.buckets)?);
+        }
+        Ok(())
     }
 }
+
+fn main() -> ExitCode {
+    aoc_common::run_with::<Day01, Day01Options>(INPUT_PATH)
+}
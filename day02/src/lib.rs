@@ -0,0 +1,542 @@
+//! Day 02: Rock Paper Scissors
+
+pub mod tournament;
+
+use std::fmt::{self, Display};
+
+use aoc_common::{
+    solution::{Answer, Solution},
+    ParseError,
+};
+use itertools::Itertools;
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+/// A hand of a cyclic game, the position of the hand in the cycle of its [`Game`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Hand(u8);
+
+/// The outcome of a round for the player of the right hand.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Lose = 0,
+    Draw = 1,
+    Win = 2,
+}
+
+impl Hand {
+    pub const ROCK: Hand = Hand(0);
+    pub const PAPER: Hand = Hand(1);
+    pub const SCISSORS: Hand = Hand(2);
+
+    /// Returns the hand at the cyclic distance from this hand in a game of `hands` hands,
+    /// positive distances beat this hand.
+    pub fn shift(&self, distance: i32, hands: u8) -> Hand {
+        Self((self.0 as i32 + distance).rem_euclid(hands as i32) as u8)
+    }
+
+    /// Pick next hand, it wins
+    pub fn win(&self) -> Hand {
+        self.shift(1, 3)
+    }
+
+    /// Pick previous hand, it loses
+    pub fn lose(&self) -> Hand {
+        self.shift(-1, 3)
+    }
+
+    /// The outcome for `right` against `left` in a game of `hands` hands.
+    ///
+    /// Each hand beats the `hands / 2` hands before it in the cycle & loses against the
+    /// `hands / 2` hands after it.
+    pub fn outcome(left: Hand, right: Hand, hands: u8) -> Outcome {
+        let distance = (right.0 as u32 + hands as u32 - left.0 as u32) % hands as u32;
+        match distance {
+            0 => Outcome::Draw,
+            d if d <= hands as u32 / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    /// Two players show their hands in rock paper scissors, outcome for right hand is counted.
+    pub fn play(left: Hand, right: Hand) -> u32 {
+        3 * Hand::outcome(left, right, 3) as u32 + right.0 as u32 + 1
+    }
+
+    /// The `right` parameter determines the outcome (`X` lose, `Y` draw, `Z` win), map to
+    /// associated hand.
+    pub fn play2(left: Hand, right: Hand) -> u32 {
+        Self::play(left, left.shift(right.0 as i32 - 1, 3))
+    }
+}
+
+/// A cyclic hand game of an odd number of hands, e.g. rock paper scissors or rock paper
+/// scissors lizard spock.
+///
+/// The strategy guide has a column for each player, the left column lists the hands of the
+/// opponent. The right column either lists the own hand or, in part 2, the cyclic distance of
+/// the own hand to the hand of the opponent, the middle symbol is a draw.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    names: Vec<String>,
+    left: Vec<String>,
+    right: Vec<String>,
+    hand_scores: Vec<u32>,
+    outcome_scores: [u32; 3],
+}
+
+impl Game {
+    /// Creates the game of the hands in cyclic order with their symbols in both columns, the
+    /// score for playing each hand & the scores of a loss, a draw & a win.
+    pub fn new(
+        names: &[&str],
+        left: &[&str],
+        right: &[&str],
+        hand_scores: &[u32],
+        outcome_scores: [u32; 3],
+    ) -> anyhow::Result<Self> {
+        let hands = names.len();
+        if hands < 3 || hands.is_multiple_of(2) || hands > u8::MAX as usize {
+            anyhow::bail!("A game needs an odd number of hands, got {}", hands);
+        }
+        for (column, len) in [
+            ("left symbols", left.len()),
+            ("right symbols", right.len()),
+            ("hand scores", hand_scores.len()),
+        ] {
+            if len != hands {
+                anyhow::bail!("Expected {} {}, got {}", hands, column, len);
+            }
+        }
+        for symbols in [names, left, right] {
+            let duplicate = (1..hands).find(|&i| symbols[..i].contains(&symbols[i]));
+            if let Some(i) = duplicate {
+                anyhow::bail!("Duplicate symbol '{}'", symbols[i]);
+            }
+        }
+
+        let owned = |symbols: &[&str]| symbols.iter().map(|s| s.to_string()).collect();
+        Ok(Self {
+            names: owned(names),
+            left: owned(left),
+            right: owned(right),
+            hand_scores: hand_scores.to_vec(),
+            outcome_scores,
+        })
+    }
+
+    /// Rock paper scissors with the symbols & scores of the puzzle.
+    pub fn rock_paper_scissors() -> Self {
+        Self::new(
+            &["Rock", "Paper", "Scissors"],
+            &["A", "B", "C"],
+            &["X", "Y", "Z"],
+            &[1, 2, 3],
+            [0, 3, 6],
+        )
+        .expect("Valid game")
+    }
+
+    /// Rock paper scissors lizard spock, the hands are scored in their usual order.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::new(
+            &["Rock", "Spock", "Paper", "Lizard", "Scissors"],
+            &["A", "B", "C", "D", "E"],
+            &["V", "W", "X", "Y", "Z"],
+            &[1, 5, 2, 4, 3],
+            [0, 3, 6],
+        )
+        .expect("Valid game")
+    }
+
+    /// The number of hands.
+    pub fn hands(&self) -> u8 {
+        self.names.len() as u8
+    }
+
+    /// The name of the hand.
+    pub fn name(&self, hand: Hand) -> &str {
+        &self.names[hand.0 as usize]
+    }
+
+    /// Two players show their hands, outcome for right hand is counted.
+    pub fn play(&self, left: Hand, right: Hand) -> u32 {
+        let outcome = Hand::outcome(left, right, self.hands());
+        self.outcome_scores[outcome as usize] + self.hand_scores[right.0 as usize]
+    }
+
+    /// The `right` parameter determines the cyclic distance of the own hand to the `left`
+    /// hand, the middle symbol is a draw.
+    pub fn play2(&self, left: Hand, right: Hand) -> u32 {
+        let distance = right.0 as i32 - self.hands() as i32 / 2;
+        self.play(left, left.shift(distance, self.hands()))
+    }
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::rock_paper_scissors()
+    }
+}
+
+/// Parses the hand of a column, the position of the symbol is the hand.
+fn parse_hand(input: &str, symbol: &str, symbols: &[String]) -> Result<Hand, ParseError> {
+    symbols
+        .iter()
+        .position(|s| s == symbol)
+        .map(|index| Hand(index as u8))
+        .ok_or_else(|| {
+            let message = format!("Expected one of {}", symbols.join(", "));
+            ParseError::at(input, symbol, message)
+        })
+}
+
+/// Parses the strategy guide from the input as list of hands to play
+///
+/// The left column `A`, `B`, `C` & the right column `X`, `Y`, `Z` both map to rock, paper &
+/// scissors.
+pub fn parse(input: &str) -> Result<Vec<(Hand, Hand)>, ParseError> {
+    parse_game(input, &Game::default())
+}
+
+/// Parses the strategy guide with the symbols of the game.
+pub fn parse_game(input: &str, game: &Game) -> Result<Vec<(Hand, Hand)>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|line| {
+            let (l, r) = line.split_once(' ').ok_or_else(|| {
+                ParseError::at(input, line, "Expected two columns separated by space")
+            })?;
+            let l = parse_hand(input, l, &game.left)?;
+            let r = parse_hand(input, r, &game.right)?;
+            Ok((l, r))
+        })
+        .collect()
+}
+
+pub fn part1(hands: &[(Hand, Hand)]) -> u32 {
+    part1_game(hands, &Game::default())
+}
+
+pub fn part2(hands: &[(Hand, Hand)]) -> u32 {
+    part2_game(hands, &Game::default())
+}
+
+/// Total score when the right column is the own hand.
+pub fn part1_game(hands: &[(Hand, Hand)], game: &Game) -> u32 {
+    hands.iter().map(|&(l, r)| game.play(l, r)).sum()
+}
+
+/// Total score when the right column is the distance of the own hand.
+pub fn part2_game(hands: &[(Hand, Hand)], game: &Game) -> u32 {
+    hands.iter().map(|&(l, r)| game.play2(l, r)).sum()
+}
+
+/// A mapping of the symbols of the right column onto hands or outcomes, scored over the whole
+/// strategy guide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    /// The index of the hand or cyclic distance each symbol maps to
+    pub targets: Vec<u8>,
+    /// The total score of the strategy guide
+    pub score: u32,
+}
+
+/// All mappings of the right column onto either hands or outcomes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mappings {
+    /// The symbols of the right column
+    pub symbols: Vec<String>,
+    /// The names of the hands or outcomes
+    pub targets: Vec<String>,
+    /// All bijections in lexicographic order, the identity is the intended mapping
+    pub mappings: Vec<Mapping>,
+}
+
+impl Mappings {
+    /// Scores all bijections of the right column, `play` scores a round with the target index.
+    fn score(
+        counts: &[Vec<u32>],
+        targets: Vec<String>,
+        symbols: &[String],
+        play: impl Fn(Hand, Hand) -> u32,
+    ) -> Self {
+        let hands = symbols.len();
+        let mappings = (0..hands as u8)
+            .permutations(hands)
+            .map(|targets| {
+                let score = counts
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(l, row)| {
+                        row.iter().enumerate().map(move |(r, &count)| (l, r, count))
+                    })
+                    .map(|(l, r, count)| count * play(Hand(l as u8), Hand(targets[r])))
+                    .sum();
+                Mapping { targets, score }
+            })
+            .collect();
+        Self {
+            symbols: symbols.to_vec(),
+            targets,
+            mappings,
+        }
+    }
+
+    /// The mapping the strategy guide intends, each symbol maps to the target at its position.
+    pub fn intended(&self) -> &Mapping {
+        &self.mappings[0]
+    }
+
+    /// The mapping with the highest score, the first one of equal scores.
+    pub fn best(&self) -> &Mapping {
+        self.mappings
+            .iter()
+            .reduce(|best, mapping| {
+                if mapping.score > best.score {
+                    mapping
+                } else {
+                    best
+                }
+            })
+            .expect("At least one mapping")
+    }
+
+    /// The mapping with the lowest score, the first one of equal scores.
+    pub fn worst(&self) -> &Mapping {
+        self.mappings
+            .iter()
+            .reduce(|worst, mapping| {
+                if mapping.score < worst.score {
+                    mapping
+                } else {
+                    worst
+                }
+            })
+            .expect("At least one mapping")
+    }
+
+    /// Describes the mapping, e.g. `X=Rock Y=Paper Z=Scissors`.
+    pub fn describe(&self, mapping: &Mapping) -> String {
+        self.symbols
+            .iter()
+            .zip(&mapping.targets)
+            .map(|(symbol, &target)| format!("{}={}", symbol, self.targets[target as usize]))
+            .join(" ")
+    }
+}
+
+/// The scores of all interpretations of the strategy guide.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    /// The right column maps to hands, as in part 1
+    pub hands: Mappings,
+    /// The right column maps to outcomes, as in part 2
+    pub outcomes: Mappings,
+    /// The expected score of playing a random hand each round
+    pub baseline: f64,
+}
+
+impl Analysis {
+    /// The score the intended mapping gains over playing random hands.
+    pub fn gain(&self, mappings: &Mappings) -> f64 {
+        mappings.intended().score as f64 - self.baseline
+    }
+}
+
+/// Returns the name of a cyclic distance, the outcome of the own hand.
+fn distance_name(distance: i32) -> String {
+    match distance {
+        -1 => "lose".to_string(),
+        0 => "draw".to_string(),
+        1 => "win".to_string(),
+        d if d < 0 => format!("lose{}", d),
+        d => format!("win+{}", d),
+    }
+}
+
+/// Scores the strategy guide for every mapping of the right column onto hands & onto outcomes.
+pub fn analyse(guide: &[(Hand, Hand)], game: &Game) -> Analysis {
+    let hands = game.hands() as usize;
+    let mut counts = vec![vec![0; hands]; hands];
+    for &(l, r) in guide {
+        counts[l.0 as usize][r.0 as usize] += 1;
+    }
+
+    let baseline = counts
+        .iter()
+        .enumerate()
+        .map(|(l, row)| {
+            let total = (0..hands)
+                .map(|h| game.play(Hand(l as u8), Hand(h as u8)))
+                .sum::<u32>();
+            row.iter().sum::<u32>() as f64 * total as f64 / hands as f64
+        })
+        .sum();
+
+    let distances = (0..hands as i32)
+        .map(|index| distance_name(index - hands as i32 / 2))
+        .collect();
+    Analysis {
+        hands: Mappings::score(&counts, game.names.clone(), &game.right, |l, r| {
+            game.play(l, r)
+        }),
+        outcomes: Mappings::score(&counts, distances, &game.right, |l, r| game.play2(l, r)),
+        baseline,
+    }
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Random baseline: {:.1}", self.baseline)?;
+        for (title, mappings) in [("hands", &self.hands), ("outcomes", &self.outcomes)] {
+            writeln!(f, "Mappings onto {}:", title)?;
+            for mapping in &mappings.mappings {
+                let mut marks = Vec::new();
+                if mapping == mappings.intended() {
+                    marks.push(format!(
+                        "intended, {:+.1} over baseline",
+                        self.gain(mappings)
+                    ));
+                }
+                if mapping == mappings.best() {
+                    marks.push("best".to_string());
+                }
+                if mapping == mappings.worst() {
+                    marks.push("worst".to_string());
+                }
+                write!(f, "  {} {:>8}", mappings.describe(mapping), mapping.score)?;
+                if !marks.is_empty() {
+                    write!(f, " ({})", marks.join(", "))?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Day 02: Rock Paper Scissors
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+
+    type Input<'a> = Vec<(Hand, Hand)>;
+    type Config = Game;
+
+    fn parse<'a>(input: &'a str, game: &Game) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_game(input, game)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, game: &Game) -> anyhow::Result<Answer> {
+        Ok(part1_game(input, game).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, game: &Game) -> anyhow::Result<Answer> {
+        Ok(part2_game(input, game).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = r#"
+        A Y
+        B X
+        C Z
+    "#;
+
+    #[test]
+    fn check_part1() {
+        assert_eq!(15, part1(&parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn check_part2() {
+        assert_eq!(12, part2(&parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn check_rock_paper_scissors_lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let hand = |name| {
+            (0..5)
+                .map(Hand)
+                .find(|&hand| game.name(hand) == name)
+                .unwrap()
+        };
+        let beats = [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ];
+        for (winner, loser) in beats {
+            assert_eq!(Outcome::Win, Hand::outcome(hand(loser), hand(winner), 5));
+            assert_eq!(Outcome::Lose, Hand::outcome(hand(winner), hand(loser), 5));
+        }
+
+        let guide = parse_game("A X\nE V\nD Z", &game).unwrap();
+        // Paper beats Rock, Rock beats Scissors, Scissors beats Lizard
+        assert_eq!(8 + 7 + 9, part1_game(&guide, &game));
+        // Rock draws Rock, Paper loses against Scissors, Rock beats Lizard
+        assert_eq!(4 + 2 + 7, part2_game(&guide, &game));
+    }
+
+    #[test]
+    fn check_analysis() {
+        let analysis = analyse(&parse(INPUT).unwrap(), &Game::default());
+        assert_eq!(15.0, analysis.baseline);
+
+        let hands = &analysis.hands;
+        assert_eq!(6, hands.mappings.len());
+        assert_eq!(15, hands.intended().score);
+        assert_eq!(0.0, analysis.gain(hands));
+        assert_eq!("X=Scissors Y=Paper Z=Rock", hands.describe(hands.best()));
+        assert_eq!(24, hands.best().score);
+        assert_eq!("X=Rock Y=Scissors Z=Paper", hands.describe(hands.worst()));
+        assert_eq!(6, hands.worst().score);
+
+        let outcomes = &analysis.outcomes;
+        assert_eq!(
+            "X=lose Y=draw Z=win",
+            outcomes.describe(outcomes.intended())
+        );
+        assert_eq!(12, outcomes.intended().score);
+        assert_eq!(-3.0, analysis.gain(outcomes));
+    }
+
+    #[test]
+    fn check_invalid_game() {
+        let error = Game::new(&["A", "B"], &["A", "B"], &["X", "Y"], &[1, 2], [0, 3, 6]);
+        assert!(error.is_err());
+        let error = Game::new(
+            &["A", "B", "A"],
+            &["A", "B", "C"],
+            &["X", "Y", "Z"],
+            &[1, 2, 3],
+            [0, 3, 6],
+        );
+        assert_eq!("Duplicate symbol 'A'", error.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn check_parse_error() {
+        assert_eq!(
+            Err(ParseError::new(2, 3, "Q", "Expected one of X, Y, Z")),
+            parse("A Y\nB Q\n")
+        );
+    }
+}
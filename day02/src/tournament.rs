@@ -0,0 +1,289 @@
+//! A round robin tournament of rock paper scissors strategies.
+//!
+//! Every strategy plays a match of a fixed number of rounds against every other strategy, each
+//! round is scored with [`Hand::play`]. All random hands are drawn from a single seeded
+//! generator, a tournament with the same seed always ends with the same standings.
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+};
+
+use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
+
+use crate::{Game, Hand};
+
+/// A single round from the point of view of one player.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub own: Hand,
+    pub opponent: Hand,
+}
+
+/// Picks the hands of a player in a match of rock paper scissors.
+pub trait Strategy {
+    /// The name shown in the standings
+    fn name(&self) -> String;
+
+    /// Picks the hand of the next round, `history` lists all previous rounds of the match.
+    fn play(&self, history: &[Round], rng: &mut dyn RngCore) -> Hand;
+}
+
+fn random_hand(rng: &mut dyn RngCore) -> Hand {
+    Hand(rng.gen_range(0..3))
+}
+
+/// Always plays the same hand.
+pub struct Always(pub Hand);
+
+impl Strategy for Always {
+    fn name(&self) -> String {
+        format!("Always {}", Game::default().name(self.0))
+    }
+
+    fn play(&self, _history: &[Round], _rng: &mut dyn RngCore) -> Hand {
+        self.0
+    }
+}
+
+/// Plays a random hand each round.
+pub struct Random;
+
+impl Strategy for Random {
+    fn name(&self) -> String {
+        "Random".to_string()
+    }
+
+    fn play(&self, _history: &[Round], rng: &mut dyn RngCore) -> Hand {
+        random_hand(rng)
+    }
+}
+
+/// Copies the last hand of the opponent, starts with a random hand.
+pub struct CopyLast;
+
+impl Strategy for CopyLast {
+    fn name(&self) -> String {
+        "Copy last".to_string()
+    }
+
+    fn play(&self, history: &[Round], rng: &mut dyn RngCore) -> Hand {
+        match history.last() {
+            Some(round) => round.opponent,
+            None => random_hand(rng),
+        }
+    }
+}
+
+/// Plays the hand that beats the last hand of the opponent, starts with a random hand.
+pub struct BeatLast;
+
+impl Strategy for BeatLast {
+    fn name(&self) -> String {
+        "Beat last".to_string()
+    }
+
+    fn play(&self, history: &[Round], rng: &mut dyn RngCore) -> Hand {
+        match history.last() {
+            Some(round) => round.opponent.win(),
+            None => random_hand(rng),
+        }
+    }
+}
+
+/// Predicts the most frequent hand of the opponent & plays the hand that beats it, starts
+/// with a random hand.
+///
+/// Of equally frequent hands the first hand in the order rock, paper, scissors is predicted.
+pub struct Frequency;
+
+impl Strategy for Frequency {
+    fn name(&self) -> String {
+        "Frequency".to_string()
+    }
+
+    fn play(&self, history: &[Round], rng: &mut dyn RngCore) -> Hand {
+        if history.is_empty() {
+            return random_hand(rng);
+        }
+
+        let mut counts = [0usize; 3];
+        for round in history {
+            counts[round.opponent.0 as usize] += 1;
+        }
+        let predicted = (0..3)
+            .rev()
+            .max_by_key(|&hand| counts[hand as usize])
+            .expect("Three hands");
+        Hand(predicted).win()
+    }
+}
+
+/// Returns one strategy of each kind, playing rock where a strategy needs a fixed hand.
+pub fn strategies() -> Vec<Box<dyn Strategy>> {
+    vec![
+        Box::new(Always(Hand::ROCK)),
+        Box::new(Random),
+        Box::new(CopyLast),
+        Box::new(BeatLast),
+        Box::new(Frequency),
+    ]
+}
+
+/// The results of a strategy over all of its matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub name: String,
+    /// The matches with a higher score than the opponent
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    /// The summed scores of all rounds of all matches
+    pub score: u32,
+}
+
+/// The standings of a tournament, best strategy first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standings(pub Vec<Standing>);
+
+/// Plays a match of the given number of rounds, returns the scores of both players.
+fn play_match(
+    left: &dyn Strategy,
+    right: &dyn Strategy,
+    rounds: usize,
+    rng: &mut dyn RngCore,
+) -> (u32, u32) {
+    let mut left_history = Vec::with_capacity(rounds);
+    let mut right_history = Vec::with_capacity(rounds);
+    let (mut left_score, mut right_score) = (0, 0);
+
+    for _ in 0..rounds {
+        let l = left.play(&left_history, rng);
+        let r = right.play(&right_history, rng);
+        left_score += Hand::play(r, l);
+        right_score += Hand::play(l, r);
+        left_history.push(Round {
+            own: l,
+            opponent: r,
+        });
+        right_history.push(Round {
+            own: r,
+            opponent: l,
+        });
+    }
+    (left_score, right_score)
+}
+
+/// Plays a match of `rounds` rounds between every pair of strategies.
+///
+/// The standings are ordered by score, then by the number of won matches, then by the order
+/// of the strategies.
+pub fn round_robin(strategies: &[Box<dyn Strategy>], rounds: usize, seed: u64) -> Standings {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut standings = strategies
+        .iter()
+        .map(|strategy| Standing {
+            name: strategy.name(),
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            score: 0,
+        })
+        .collect::<Vec<_>>();
+
+    for l in 0..strategies.len() {
+        for r in l + 1..strategies.len() {
+            let (left, right) = play_match(&*strategies[l], &*strategies[r], rounds, &mut rng);
+            standings[l].score += left;
+            standings[r].score += right;
+            match left.cmp(&right) {
+                Ordering::Greater => {
+                    standings[l].wins += 1;
+                    standings[r].losses += 1;
+                }
+                Ordering::Less => {
+                    standings[l].losses += 1;
+                    standings[r].wins += 1;
+                }
+                Ordering::Equal => {
+                    standings[l].draws += 1;
+                    standings[r].draws += 1;
+                }
+            }
+        }
+    }
+
+    standings.sort_by(|a, b| b.score.cmp(&a.score).then(b.wins.cmp(&a.wins)));
+    Standings(standings)
+}
+
+impl Display for Standings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .0
+            .iter()
+            .map(|standing| standing.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("Strategy".len());
+
+        writeln!(
+            f,
+            "{:>2} | {:<width$} | {:>3} | {:>3} | {:>3} | {:>8}",
+            "#", "Strategy", "W", "D", "L", "Score"
+        )?;
+        writeln!(
+            f,
+            "{:-<2}-+-{:-<width$}-+-{:-<3}-+-{:-<3}-+-{:-<3}-+-{:-<8}",
+            "", "", "", "", "", ""
+        )?;
+        for (rank, standing) in self.0.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>2} | {:<width$} | {:>3} | {:>3} | {:>3} | {:>8}",
+                rank + 1,
+                standing.name,
+                standing.wins,
+                standing.draws,
+                standing.losses,
+                standing.score
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_frequency_prediction() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let history =
+            [Hand::SCISSORS, Hand::PAPER, Hand::SCISSORS, Hand::PAPER].map(|opponent| Round {
+                own: Hand::ROCK,
+                opponent,
+            });
+        // paper & scissors are tied, paper is predicted
+        assert_eq!(Hand::SCISSORS, Frequency.play(&history, &mut rng));
+        assert_eq!(Hand::ROCK, Frequency.play(&history[..1], &mut rng));
+    }
+
+    #[test]
+    fn check_round_robin() {
+        let standings = round_robin(&strategies(), 100, 7);
+        assert_eq!(standings, round_robin(&strategies(), 100, 7));
+
+        let always = standings
+            .0
+            .iter()
+            .find(|standing| standing.name == "Always Rock")
+            .unwrap();
+        assert_eq!(0, always.wins);
+        assert!(standings.0.windows(2).all(|w| w[0].score >= w[1].score));
+        for standing in &standings.0 {
+            assert_eq!(4, standing.wins + standing.draws + standing.losses);
+        }
+    }
+}
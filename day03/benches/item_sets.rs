@@ -0,0 +1,129 @@
+//! Compares the bitset item sets with the former `HashSet<char>` implementation on large
+//! synthetic inputs.
+
+use std::collections::HashSet;
+
+use aoc_2022_day_03::{parse, part1, part2};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use itertools::Itertools;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A simple LCG, the generated input is reproducible.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (self.0 >> 33) as usize % bound
+    }
+
+    fn shuffle(&mut self, items: &mut [u8]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.next(i + 1));
+        }
+    }
+}
+
+/// Generates `count` rucksacks of random items.
+///
+/// As in the puzzle the compartments of each rucksack share exactly one item & each group of
+/// three rucksacks shares exactly one badge.
+fn synthetic_input(count: usize) -> String {
+    let mut rng = Lcg(0x2545_f491_4f6c_dd1d);
+    let mut rucksacks = Vec::with_capacity(count);
+    while rucksacks.len() < count {
+        let mut items = ITEMS.to_vec();
+        rng.shuffle(&mut items);
+        // the badge is the first item, the other items are split into a pool per rucksack
+        let badge = items[0];
+        for pool in items[1..].chunks(17) {
+            // the shared item is the first of the pool, the others are either left or right
+            let (shared, left, right) = (pool[0], &pool[1..9], &pool[9..]);
+            let half = 8 + rng.next(12);
+            let mut first = vec![shared, badge];
+            let mut second = vec![shared];
+            first.extend((first.len()..half).map(|_| left[rng.next(left.len())]));
+            second.extend((second.len()..half).map(|_| right[rng.next(right.len())]));
+            rng.shuffle(&mut first);
+            rng.shuffle(&mut second);
+            first.extend(second);
+            rucksacks.push(String::from_utf8(first).expect("ASCII items"));
+        }
+    }
+    rucksacks.truncate(count);
+    rucksacks.join("\n")
+}
+
+/// The former priority lookup.
+fn get_priority(c: char) -> u32 {
+    match c {
+        'a'..='z' => c as u32 - 96,
+        'A'..='Z' => c as u32 - 38,
+        _ => panic!("Unknown char found"),
+    }
+}
+
+/// The former `HashSet<char>` implementation of part 1.
+fn hash_set_part1(rucksacks: &[&str]) -> u32 {
+    rucksacks
+        .iter()
+        .map(|line| line.split_at(line.len() / 2))
+        .map(|(left, right)| {
+            let left = left.chars().collect::<HashSet<_>>();
+            let right = right.chars().collect::<HashSet<_>>();
+            *left.intersection(&right).next().unwrap()
+        })
+        .map(get_priority)
+        .sum()
+}
+
+/// The former `HashSet<char>` implementation of part 2.
+fn hash_set_part2(rucksacks: &[&str]) -> u32 {
+    rucksacks
+        .iter()
+        .map(|&s| s.chars().collect::<HashSet<_>>())
+        .chunks(3)
+        .into_iter()
+        .map(|group| {
+            *group
+                .reduce(|result: HashSet<char>, rhs| result.intersection(&rhs).cloned().collect())
+                .unwrap()
+                .iter()
+                .next()
+                .unwrap()
+        })
+        .map(get_priority)
+        .sum()
+}
+
+fn item_sets(c: &mut Criterion) {
+    for count in [30_000, 300_000] {
+        let input = synthetic_input(count);
+        let rucksacks = parse(&input).expect("Valid synthetic input");
+        assert_eq!(Ok(hash_set_part1(&rucksacks)), part1(&rucksacks));
+        assert_eq!(Ok(hash_set_part2(&rucksacks)), part2(&rucksacks));
+
+        let mut group = c.benchmark_group(format!("day03/{}", count));
+        group.sample_size(20);
+        group.bench_function(BenchmarkId::new("part1", "hash_set"), |b| {
+            b.iter(|| hash_set_part1(&rucksacks))
+        });
+        group.bench_function(BenchmarkId::new("part1", "item_set"), |b| {
+            b.iter(|| part1(&rucksacks))
+        });
+        group.bench_function(BenchmarkId::new("part2", "hash_set"), |b| {
+            b.iter(|| hash_set_part2(&rucksacks))
+        });
+        group.bench_function(BenchmarkId::new("part2", "item_set"), |b| {
+            b.iter(|| part2(&rucksacks))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, item_sets);
+criterion_main!(benches);
@@ -0,0 +1,520 @@
+//! Day 03: Rucksack Reorganization
+
+use std::{
+    fmt::{self, Display},
+    ops::BitAnd,
+};
+
+use aoc_common::{
+    parse,
+    solution::{Answer, Solution},
+    ParseError,
+};
+use itertools::Itertools;
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+/// The number of compartments per rucksack & of rucksacks per group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    compartments: usize,
+    group_size: usize,
+}
+
+impl Config {
+    /// Creates the config, both numbers must be at least `1`.
+    pub fn new(compartments: usize, group_size: usize) -> anyhow::Result<Self> {
+        if compartments == 0 {
+            anyhow::bail!("A rucksack needs at least one compartment");
+        }
+        if group_size == 0 {
+            anyhow::bail!("A group needs at least one rucksack");
+        }
+        Ok(Self {
+            compartments,
+            group_size,
+        })
+    }
+
+    pub fn compartments(&self) -> usize {
+        self.compartments
+    }
+
+    pub fn group_size(&self) -> usize {
+        self.group_size
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            compartments: 2,
+            group_size: 3,
+        }
+    }
+}
+
+/// Parses the contents of the rucksack compartments
+///
+/// The compartments are filled evenly
+/// all: 'vJrwpWtwJgWrhcsFMMfFFhFp'
+/// 1st: 'vJrwpWtwJgWr'
+/// 2nd:             'hcsFMMfFFhFp'
+///
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    parse_with(input, &Config::default())
+}
+
+/// Parses the rucksacks, each rucksack must divide evenly into its compartments & the groups
+/// must be complete.
+pub fn parse_with<'a>(input: &'a str, config: &Config) -> Result<Vec<&'a str>, ParseError> {
+    let rucksacks = input
+        .lines()
+        .map(str::trim)
+        .filter(|&line| !line.is_empty())
+        .map(|line| {
+            let unknown = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic());
+            if let Some((index, c)) = unknown {
+                let item = &line[index..index + c.len_utf8()];
+                return Err(ParseError::at(input, item, "Expected an item a-z or A-Z"));
+            }
+            if line.len() % config.compartments != 0 {
+                let message = match config.compartments {
+                    2 => "Expected an even number of items".to_string(),
+                    n => format!("Expected a multiple of {} items", n),
+                };
+                return Err(ParseError::at(input, line, message));
+            }
+            Ok(line)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let incomplete = rucksacks.len() % config.group_size;
+    if incomplete != 0 {
+        let first = rucksacks[rucksacks.len() - incomplete];
+        let message = format!(
+            "Expected groups of {} rucksacks, the last group has {}",
+            config.group_size, incomplete
+        );
+        return Err(ParseError::at(input, first, message));
+    }
+    Ok(rucksacks)
+}
+
+/// A character that is no item, items are `a` to `z` & `A` to `Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownItem(pub char);
+
+impl Display for UnknownItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown item '{}', expected a-z or A-Z", self.0)
+    }
+}
+
+impl std::error::Error for UnknownItem {}
+
+/// Returns the priority of the item, `a` to `z` have the priorities 1 to 26, `A` to `Z` 27 to
+/// 52.
+pub fn get_priority(c: char) -> Result<u32, UnknownItem> {
+    match c {
+        'a'..='z' => Ok(c as u32 - 96),
+        'A'..='Z' => Ok(c as u32 - 38),
+        _ => Err(UnknownItem(c)),
+    }
+}
+
+/// A set of item types, there are only 52 of them.
+///
+/// Each item is a single bit of the mask, the bit index is the priority of the item, `a` to `z`
+/// have the priorities 1 to 26, `A` to `Z` 27 to 52. Intersecting two sets is a single AND.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    /// Creates the set of all items, fails on a character that is no item.
+    pub fn new(items: &str) -> Result<Self, UnknownItem> {
+        items
+            .chars()
+            .try_fold(0, |mask, item| Ok(mask | 1 << get_priority(item)?))
+            .map(Self)
+    }
+
+    /// Returns the number of different items.
+    pub fn len(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the lowest priority of all items, `None` for the empty set.
+    pub fn priority(&self) -> Option<u32> {
+        (!self.is_empty()).then(|| self.0.trailing_zeros())
+    }
+
+    /// Returns the priorities of all items, lowest first.
+    pub fn priorities(&self) -> impl Iterator<Item = u32> {
+        let mut mask = self.0;
+        std::iter::from_fn(move || {
+            let priority = (mask != 0).then(|| mask.trailing_zeros())?;
+            mask &= mask - 1;
+            Some(priority)
+        })
+    }
+
+    /// Returns the summed priorities of all items.
+    pub fn priority_sum(&self) -> u32 {
+        self.priorities().sum()
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl Display for ItemSet {
+    /// Lists the items ordered by priority, e.g. `pL`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for priority in self.priorities() {
+            let item = match priority {
+                1..=26 => b'a' + priority as u8 - 1,
+                _ => b'A' + priority as u8 - 27,
+            };
+            write!(f, "{}", item as char)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes which items are shared, when not exactly one item is shared.
+fn write_shared(f: &mut fmt::Formatter<'_>, what: &str, shared: &ItemSet) -> fmt::Result {
+    match shared.len() {
+        0 => write!(f, "{} shares no item", what),
+        _ => write!(f, "{} shares several items '{}'", what, shared),
+    }
+}
+
+/// Why the priorities of the shared items can't be summed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PriorityError {
+    /// A character that is no item
+    Unknown(UnknownItem),
+    /// The compartments of the rucksack don't share exactly one item, the index starts at `0`
+    Rucksack { index: usize, shared: ItemSet },
+    /// The rucksacks of the group don't share exactly one item, the index starts at `0`
+    Group { index: usize, shared: ItemSet },
+}
+
+impl From<UnknownItem> for PriorityError {
+    fn from(item: UnknownItem) -> Self {
+        PriorityError::Unknown(item)
+    }
+}
+
+impl Display for PriorityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PriorityError::Unknown(item) => write!(f, "{}", item),
+            PriorityError::Rucksack { index, shared } => {
+                write_shared(f, &format!("Rucksack #{}", index), shared)?;
+                write!(f, ", expected exactly one")
+            }
+            PriorityError::Group { index, shared } => {
+                write_shared(f, &format!("Group #{}", index), shared)?;
+                write!(f, ", expected exactly one")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PriorityError {}
+
+/// Returns the items shared by all parts, the empty set for no parts.
+fn shared_items<'a>(parts: impl IntoIterator<Item = &'a str>) -> Result<ItemSet, UnknownItem> {
+    let mut shared = None;
+    for part in parts {
+        let items = ItemSet::new(part)?;
+        shared = Some(shared.map_or(items, |shared| shared & items));
+    }
+    Ok(shared.unwrap_or_default())
+}
+
+/// Returns the items shared by all compartments of the rucksack.
+fn shared_by_compartments(rucksack: &str, config: &Config) -> Result<ItemSet, UnknownItem> {
+    let size = (rucksack.len() / config.compartments).max(1);
+    let compartments = rucksack
+        .as_bytes()
+        .chunks(size)
+        .map(String::from_utf8_lossy)
+        .collect::<Vec<_>>();
+    shared_items(compartments.iter().map(|compartment| compartment.as_ref()))
+}
+
+/// Returns the groups of rucksacks with the items shared by all rucksacks of each group.
+fn shared_by_groups<'a>(
+    rucksacks: &'a [&'a str],
+    config: &Config,
+) -> impl Iterator<Item = Result<(&'a [&'a str], ItemSet), UnknownItem>> + 'a {
+    rucksacks
+        .chunks(config.group_size)
+        .map(|group| Ok((group, shared_items(group.iter().copied())?)))
+}
+
+/// Finds the duplicate item of each rucksack, calculates the total priority of all.
+/// Each rucksack is split into two same size compartments.
+pub fn part1(rucksacks: &[&str]) -> Result<u32, PriorityError> {
+    part1_with(rucksacks, &Config::default())
+}
+
+/// Find the badge in each group of elves. Each group consists of three elves. All three
+/// elves' rucksack contain the same badge.
+pub fn part2(rucksacks: &[&str]) -> Result<u32, PriorityError> {
+    part2_with(rucksacks, &Config::default())
+}
+
+/// Sums the priorities of the item in all compartments of each rucksack, fails when a
+/// rucksack doesn't share exactly one item, see [`audit`].
+pub fn part1_with(rucksacks: &[&str], config: &Config) -> Result<u32, PriorityError> {
+    rucksacks
+        .iter()
+        .enumerate()
+        .map(|(index, rucksack)| {
+            let shared = shared_by_compartments(rucksack, config)?;
+            match shared.priority() {
+                Some(priority) if shared.len() == 1 => Ok(priority),
+                _ => Err(PriorityError::Rucksack { index, shared }),
+            }
+        })
+        .sum()
+}
+
+/// Sums the priorities of the badges, the item in all rucksacks of each group, fails when a
+/// group doesn't share exactly one item, see [`audit`].
+pub fn part2_with(rucksacks: &[&str], config: &Config) -> Result<u32, PriorityError> {
+    shared_by_groups(rucksacks, config)
+        .enumerate()
+        .map(|(index, group)| {
+            let (_, shared) = group?;
+            match shared.priority() {
+                Some(priority) if shared.len() == 1 => Ok(priority),
+                _ => Err(PriorityError::Group { index, shared }),
+            }
+        })
+        .sum()
+}
+
+/// A rucksack or a group of rucksacks that doesn't share exactly one item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// The compartments of the rucksack in the given line
+    Rucksack { line: usize, shared: ItemSet },
+    /// The rucksacks of the group in the given lines
+    Group { lines: Vec<usize>, shared: ItemSet },
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::Rucksack { line, shared } => {
+                write_shared(f, &format!("Rucksack in line {}", line), shared)
+            }
+            Violation::Group { lines, shared } => write_shared(
+                f,
+                &format!("Group in lines {}", lines.iter().join(", ")),
+                shared,
+            ),
+        }
+    }
+}
+
+/// Lists all rucksacks & groups that don't share exactly one item, `rucksacks` are sub slices
+/// of the puzzle `input`.
+pub fn audit(
+    input: &str,
+    rucksacks: &[&str],
+    config: &Config,
+) -> Result<Vec<Violation>, UnknownItem> {
+    let line = |rucksack: &str| {
+        input[..parse::offset_of(input, rucksack)]
+            .matches('\n')
+            .count()
+            + 1
+    };
+
+    let mut violations = Vec::new();
+    for &rucksack in rucksacks {
+        let shared = shared_by_compartments(rucksack, config)?;
+        if shared.len() != 1 {
+            violations.push(Violation::Rucksack {
+                line: line(rucksack),
+                shared,
+            });
+        }
+    }
+    for group in shared_by_groups(rucksacks, config) {
+        let (group, shared) = group?;
+        if shared.len() != 1 {
+            violations.push(Violation::Group {
+                lines: group.iter().map(|rucksack| line(rucksack)).collect(),
+                shared,
+            });
+        }
+    }
+    Ok(violations)
+}
+
+/// Day 03: Rucksack Reorganization
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+
+    type Input<'a> = Vec<&'a str>;
+    type Config = Config;
+
+    fn parse<'a>(input: &'a str, config: &Config) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse_with(input, config)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, config: &Config) -> anyhow::Result<Answer> {
+        Ok(part1_with(input, config)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>, config: &Config) -> anyhow::Result<Answer> {
+        Ok(part2_with(input, config)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = r#"
+        vJrwpWtwJgWrhcsFMMfFFhFp
+        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+        PmmdzqPrVvPwwTWBwg
+        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+        ttgJtRGJQctTZtZT
+        CrZsJsPPZsGzwwsLwLmpwMDw
+    "#;
+
+    #[test]
+    fn check_part1() {
+        assert_eq!(Ok(157), part1(&parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn check_part2() {
+        assert_eq!(Ok(70), part2(&parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn check_config() -> anyhow::Result<()> {
+        let config = Config::new(4, 2)?;
+        let rucksacks = parse_with("abcbAbdb\nbxbybzbw", &config)?;
+        // `b` is in all compartments of both rucksacks & in both rucksacks
+        assert_eq!(Ok(4), part1_with(&rucksacks, &config));
+        assert_eq!(Ok(2), part2_with(&rucksacks, &config));
+
+        assert!(Config::new(0, 3).is_err());
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                1,
+                "abc",
+                "Expected a multiple of 4 items"
+            )),
+            parse_with("abcd\nabc\n", &config)
+        );
+        assert_eq!(
+            Err(ParseError::new(2, 4, "é", "Expected an item a-z or A-Z")),
+            parse("abcd\nabcé\n")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                4,
+                1,
+                "abcd",
+                "Expected groups of 3 rucksacks, the last group has 1"
+            )),
+            parse_with("ab\nab\nab\nabcd", &Config::default())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_item_set() -> Result<(), UnknownItem> {
+        let left = ItemSet::new("vJrwpWtwJgWr")?;
+        let right = ItemSet::new("hcsFMMfFFhFp")?;
+        assert_eq!(8, left.len());
+        assert_eq!(Some(16), (left & right).priority());
+        assert_eq!(None, (ItemSet::new("a")? & ItemSet::new("Z")?).priority());
+        assert_eq!(1 + 27 + 52, ItemSet::new("aAZa")?.priority_sum());
+        assert_eq!("aAZ", ItemSet::new("ZaA")?.to_string());
+        assert_eq!(Err(UnknownItem('1')), ItemSet::new("a1"));
+        assert_eq!(Err(UnknownItem('-')), get_priority('-'));
+        Ok(())
+    }
+
+    #[test]
+    fn check_single_shared_item() -> anyhow::Result<()> {
+        let input = "abcaxy\nabcdef\nxyzabz\n";
+        let rucksacks = parse(input)?;
+        let err = part1(&rucksacks).unwrap_err();
+        assert_eq!(
+            PriorityError::Rucksack {
+                index: 1,
+                shared: ItemSet::default()
+            },
+            err
+        );
+        assert_eq!(
+            "Rucksack #1 shares no item, expected exactly one",
+            err.to_string()
+        );
+        assert_eq!(
+            Err(PriorityError::Group {
+                index: 0,
+                shared: ItemSet::new("ab")?
+            }),
+            part2(&rucksacks)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_audit() -> anyhow::Result<()> {
+        let input = "abcaxy\nabcdef\nxyzabz\n";
+        let rucksacks = parse(input)?;
+        let violations = audit(input, &rucksacks, &Config::default())?;
+        assert_eq!(
+            vec![
+                Violation::Rucksack {
+                    line: 2,
+                    shared: ItemSet::default()
+                },
+                Violation::Group {
+                    lines: vec![1, 2, 3],
+                    shared: ItemSet::new("ab")?
+                },
+            ],
+            violations
+        );
+        assert_eq!(
+            "Rucksack in line 2 shares no item",
+            violations[0].to_string()
+        );
+        assert_eq!(
+            "Group in lines 1, 2, 3 shares several items 'ab'",
+            violations[1].to_string()
+        );
+        Ok(())
+    }
+}
@@ -1,94 +1,41 @@
 //! Day 03: Rucksack Reorganization
 
-use std::collections::HashSet;
-
-use itertools::Itertools;
-
-/// Parses the contents of the rucksack compartments
-///
-/// The compartments are filled evenly
-/// all: 'vJrwpWtwJgWrhcsFMMfFFhFp'
-/// 1st: 'vJrwpWtwJgWr'
-/// 2nd:             'hcsFMMfFFhFp'
-///
-fn parse(input: &str) -> Vec<&str> {
-    input
-        .lines()
-        .map(str::trim)
-        .filter(|&line| !line.is_empty())
-        .collect_vec()
+use std::process::ExitCode;
+
+use aoc_2022_day_03::{audit, Config, Day03, INPUT_PATH};
+use aoc_common::Options;
+
+/// The options of day 03.
+#[derive(Debug, clap::Args)]
+struct Day03Options {
+    /// Lists every rucksack & group that doesn't share exactly one item
+    #[arg(long)]
+    audit: bool,
+    /// The number of equally sized compartments of each rucksack
+    #[arg(long, default_value_t = 2)]
+    compartments: usize,
+    /// The number of rucksacks of each group
+    #[arg(long, default_value_t = 3)]
+    group_size: usize,
 }
 
-fn get_priority(c: char) -> u32 {
-    match c {
-        'a'..='z' => c as u32 - 96,
-        'A'..='Z' => c as u32 - 38,
-        _ => panic!("Unknown char found"),
+impl Options<Day03> for Day03Options {
+    fn config(&self) -> anyhow::Result<Config> {
+        Config::new(self.compartments, self.group_size)
     }
-}
-
-/// Finds all duplicate items, calculates the total priority of all.
-/// Each rucksack is split into two same size compartments.
-fn part1(rucksacks: &[&str]) -> u32 {
-    rucksacks
-        .iter()
-        .map(|line| line.split_at(line.len() / 2))
-        .map(|(left, right)| {
-            let left = left.chars().collect::<HashSet<_>>();
-            let right = right.chars().collect::<HashSet<_>>();
-            left.intersection(&right).next().unwrap().clone()
-        })
-        .map(get_priority)
-        .sum()
-}
-
-/// Find the badge in each group of elves. Each group consists of three elves. All three
-/// elves' rucksack contain the same badge.
-fn part2(rucksacks: &[&str]) -> u32 {
-    rucksacks
-        .iter()
-        .map(|&s| s.chars().collect::<HashSet<_>>())
-        .chunks(3)
-        .into_iter()
-        .map(|group| {
-            group
-                .reduce(|result: HashSet<char>, rhs| result.intersection(&rhs).cloned().collect())
-                .unwrap()
-                .iter()
-                .next()
-                .unwrap()
-                .clone()
-        })
-        .map(get_priority)
-        .sum()
-}
 
-fn main() {
-    let input = parse(include_str!("input.txt"));
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::*;
-
-    const INPUT: &str = r#"
-        vJrwpWtwJgWrhcsFMMfFFhFp
-        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-        PmmdzqPrVvPwwTWBwg
-        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-        ttgJtRGJQctTZtZT
-        CrZsJsPPZsGzwwsLwLmpwMDw
-    "#;
-
-    #[test]
-    fn check_part1() {
-        assert_eq!(157, part1(&parse(INPUT)));
+    fn inspect(&self, text: &str, rucksacks: &Vec<&str>, config: &Config) -> anyhow::Result<()> {
+        if self.audit {
+            let violations = audit(text, rucksacks, config)?;
+            for violation in &violations {
+                println!("{}", violation);
+            }
+            println!("{} violations\n", violations.len());
+        }
+        Ok(())
     }
+}
 
-    #[test]
-    fn check_part2() {
-        assert_eq!(70, part2(&parse(INPUT)));
-    }
+fn main() -> ExitCode {
+    aoc_common::run_with::<Day03, Day03Options>(INPUT_PATH)
 }
@@ -0,0 +1,482 @@
+//! Day 04: Camp Cleanup
+
+use std::fmt::{self, Display};
+
+use aoc_common::{
+    parse,
+    solution::{Answer, Solution},
+    ParseError,
+};
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+/// The sections of a team of elves, one range per member.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Assignment {
+    pub ranges: Vec<Range>,
+}
+
+impl Assignment {
+    pub fn new(ranges: Vec<Range>) -> Self {
+        Self { ranges }
+    }
+
+    /// Returns `true` if the range of one member contains the ranges of all others.
+    pub fn contains_all(&self) -> bool {
+        self.ranges
+            .iter()
+            .any(|range| self.ranges.iter().all(|other| range.contains(other)))
+    }
+
+    /// Returns `true` if the ranges of every pair of members intersect.
+    ///
+    /// Ranges intersect pairwise when the highest minimum is not above the lowest maximum.
+    pub fn pairwise_intersect(&self) -> bool {
+        let min = self.ranges.iter().map(|range| range.min).max();
+        let max = self.ranges.iter().map(|range| range.max).min();
+        min <= max
+    }
+
+    /// Returns the indices of all members whose sections are all covered by the rest of the
+    /// team.
+    ///
+    /// Each member is checked on its own, of two members with the same range both are redundant.
+    pub fn redundant(&self) -> Vec<usize> {
+        (0..self.ranges.len())
+            .filter(|&member| {
+                let rest = IntervalSet::new(
+                    self.ranges
+                        .iter()
+                        .enumerate()
+                        .filter(|&(index, _)| index != member)
+                        .map(|(_, range)| *range),
+                );
+                IntervalSet::new([self.ranges[member]])
+                    .difference(&rest)
+                    .is_empty()
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Range {
+    /// The lower or minimum bound
+    min: u32,
+    /// The upper or maximum bound
+    max: u32,
+}
+
+impl Range {
+    pub fn new(min: u32, max: u32) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns `true` if the given [`Range`] is completely contained
+    pub fn contains(&self, rhs: &Self) -> bool {
+        self.min <= rhs.min && self.max >= rhs.max
+    }
+
+    /// Returns `true` if both ranges intersect with each other.
+    ///
+    /// Both sections intersect when any bound is inside the bounds of the other.
+    pub fn intersect(&self, rhs: &Self) -> bool {
+        self.max >= rhs.min && rhs.max >= self.min
+    }
+
+    /// Returns `true` if the range has no sections, the minimum is above the maximum.
+    pub fn is_empty(&self) -> bool {
+        self.min > self.max
+    }
+
+    /// Returns the number of sections.
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            (self.max - self.min) as u64 + 1
+        }
+    }
+}
+
+impl Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.min == self.max {
+            true => write!(f, "{}", self.min),
+            false => write!(f, "{}-{}", self.min, self.max),
+        }
+    }
+}
+
+/// A set of sections, stored as normalised list of ranges.
+///
+/// The ranges are sorted, neither overlap nor touch each other & are never empty, e.g. the
+/// ranges `2-4`, `3-6` & `7-8` are normalised to `2-8`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<Range>,
+}
+
+impl IntervalSet {
+    /// Creates the set of all sections of the given ranges.
+    pub fn new(ranges: impl IntoIterator<Item = Range>) -> Self {
+        let mut ranges = ranges
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .collect::<Vec<_>>();
+        ranges.sort_unstable_by_key(|range| range.min);
+
+        let mut normalised: Vec<Range> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match normalised.last_mut() {
+                Some(last) if range.min as u64 <= last.max as u64 + 1 => {
+                    last.max = last.max.max(range.max);
+                }
+                _ => normalised.push(range),
+            }
+        }
+        Self { ranges: normalised }
+    }
+
+    /// Returns the normalised ranges.
+    pub fn ranges(&self) -> &[Range] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the number of sections.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(Range::len).sum()
+    }
+
+    /// Returns `true` if the section is in the set.
+    pub fn contains(&self, section: u32) -> bool {
+        let index = self.ranges.partition_point(|range| range.max < section);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.min <= section)
+    }
+
+    /// Returns the sections in either set.
+    pub fn union(&self, rhs: &Self) -> Self {
+        Self::new(self.ranges.iter().chain(&rhs.ranges).copied())
+    }
+
+    /// Returns the sections in both sets.
+    pub fn intersection(&self, rhs: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut l, mut r) = (0, 0);
+        while let (Some(left), Some(right)) = (self.ranges.get(l), rhs.ranges.get(r)) {
+            let range = Range::new(left.min.max(right.min), left.max.min(right.max));
+            if !range.is_empty() {
+                ranges.push(range);
+            }
+            if left.max < right.max {
+                l += 1;
+            } else {
+                r += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Returns the sections of this set that are not in the other set.
+    pub fn difference(&self, rhs: &Self) -> Self {
+        self.intersection(&rhs.complement(Range::new(0, u32::MAX)))
+    }
+
+    /// Returns the sections within the bounds that are not in this set.
+    pub fn complement(&self, bounds: Range) -> Self {
+        let mut ranges = Vec::new();
+        let mut next = bounds.min as u64;
+        for range in &self.ranges {
+            if range.min as u64 > next {
+                ranges.push(Range::new(next as u32, range.min - 1));
+            }
+            next = next.max(range.max as u64 + 1);
+        }
+        if next <= bounds.max as u64 {
+            ranges.push(Range::new(next as u32, bounds.max));
+        }
+        Self { ranges }.intersection(&Self::new([bounds]))
+    }
+}
+
+impl Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "none");
+        }
+        for (index, range) in self.ranges.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", range)?;
+        }
+        Ok(())
+    }
+}
+
+/// Which sections are covered by the elves of all assignments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    /// The range from the lowest to the highest assigned section
+    pub bounds: Option<Range>,
+    /// The sections within the bounds no elf is assigned to
+    pub uncovered: IntervalSet,
+    /// The sections assigned to more than one elf
+    pub duplicated: IntervalSet,
+    /// The total number of section assignments beyond the first of each section
+    pub duplicated_work: u64,
+}
+
+/// Sweeps over the ranges of all elves to find uncovered & duplicated sections.
+pub fn coverage(assignments: &[Assignment]) -> Coverage {
+    let ranges = assignments
+        .iter()
+        .flat_map(|assignment| assignment.ranges.iter().copied())
+        .filter(|range| !range.is_empty())
+        .collect::<Vec<_>>();
+
+    // each range starts at its minimum & ends before the section after its maximum
+    let mut events = ranges
+        .iter()
+        .flat_map(|range| [(range.min as u64, 1), (range.max as u64 + 1, -1)])
+        .collect::<Vec<(u64, i64)>>();
+    events.sort_unstable();
+
+    let mut duplicated = Vec::new();
+    let mut duplicated_work = 0;
+    let (mut elves, mut start) = (0, 0);
+    for (section, delta) in events {
+        if section > start && elves > 1 {
+            duplicated.push(Range::new(start as u32, (section - 1) as u32));
+            duplicated_work += (elves - 1) as u64 * (section - start);
+        }
+        elves += delta;
+        start = section;
+    }
+
+    let bounds = ranges
+        .iter()
+        .map(|range| range.min)
+        .min()
+        .zip(ranges.iter().map(|range| range.max).max())
+        .map(|(min, max)| Range::new(min, max));
+    let covered = IntervalSet::new(ranges);
+    Coverage {
+        bounds,
+        uncovered: bounds.map_or_else(IntervalSet::default, |bounds| covered.complement(bounds)),
+        duplicated: IntervalSet::new(duplicated),
+        duplicated_work,
+    }
+}
+
+impl Display for Coverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.bounds {
+            Some(bounds) => writeln!(f, "Sections: {}", bounds)?,
+            None => writeln!(f, "Sections: none")?,
+        }
+        writeln!(
+            f,
+            "Uncovered: {} ({} sections)",
+            self.uncovered,
+            self.uncovered.len()
+        )?;
+        writeln!(
+            f,
+            "Covered several times: {} ({} sections)",
+            self.duplicated,
+            self.duplicated.len()
+        )?;
+        writeln!(f, "Duplicated work: {} sections", self.duplicated_work)
+    }
+}
+
+/// Parses a range of sections `min-max`, a sub slice of the puzzle `input`.
+fn parse_range(input: &str, range: &str) -> Result<Range, ParseError> {
+    let (min, max) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(input, range, "Expected a range 'min-max'"))?;
+    Ok(Range::new(
+        parse::number(input, min)?,
+        parse::number(input, max)?,
+    ))
+}
+
+/// Returns the total number of overlapping assignments, where one part overlaps completely
+pub fn part1(sections: &[Assignment]) -> usize {
+    sections
+        .iter()
+        .filter(|&assignment| assignment.contains_all())
+        .count()
+}
+
+/// Returns the total number of any overlapping assignments
+pub fn part2(sections: &[Assignment]) -> usize {
+    sections
+        .iter()
+        .filter(|&assignment| assignment.pairwise_intersect())
+        .count()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Assignment>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|line| {
+            if !line.contains(',') {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "Expected at least two ranges separated by ','",
+                ));
+            }
+            let ranges = line
+                .split(',')
+                .map(|range| parse_range(input, range))
+                .collect::<Result<_, _>>()?;
+            Ok(Assignment::new(ranges))
+        })
+        .collect()
+}
+
+/// Day 04: Camp Cleanup
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type Input<'a> = Vec<Assignment>;
+    type Config = ();
+
+    fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = r#"
+        2-4,6-8
+        2-3,4-5
+        5-7,7-9
+        2-8,3-7
+        6-6,4-6
+        2-6,4-8
+    "#;
+
+    #[test]
+    fn check_range_bounds() {
+        let l = Range::new(2, 8);
+        let r = Range::new(3, 7);
+        assert!(l.contains(&r));
+        assert!(!r.contains(&l));
+    }
+
+    #[test]
+    fn check_range_intersects() {
+        let l = Range::new(5, 7);
+        let r = Range::new(7, 9);
+        assert!(l.intersect(&r));
+        assert!(r.intersect(&l));
+    }
+
+    #[test]
+    fn check_interval_set() {
+        let set = IntervalSet::new([Range::new(7, 8), Range::new(2, 4), Range::new(3, 6)]);
+        assert_eq!(&[Range::new(2, 8)], set.ranges());
+        assert_eq!(7, set.len());
+
+        let l = IntervalSet::new([Range::new(1, 3), Range::new(6, 9)]);
+        let r = IntervalSet::new([Range::new(3, 7), Range::new(9, 9), Range::new(4, 2)]);
+        assert_eq!("1-9", l.union(&r).to_string());
+        assert_eq!("3, 6-7, 9", l.intersection(&r).to_string());
+        assert_eq!("1-2, 8", l.difference(&r).to_string());
+        assert_eq!("4-5, 10", l.complement(Range::new(2, 10)).to_string());
+        assert_eq!(
+            "0-2, 8, 10-4294967295",
+            r.complement(Range::new(0, u32::MAX)).to_string()
+        );
+        assert!(l.contains(8) && !l.contains(4) && !l.contains(10));
+    }
+
+    #[test]
+    fn check_coverage() {
+        let coverage = coverage(&parse(INPUT).unwrap());
+        assert_eq!(Some(Range::new(2, 9)), coverage.bounds);
+        assert!(coverage.uncovered.is_empty());
+        assert_eq!("2-8", coverage.duplicated.to_string());
+        assert_eq!(34, coverage.duplicated_work);
+
+        let coverage = crate::coverage(&parse("1-2,8-9\n2-3,5-5").unwrap());
+        assert_eq!("4, 6-7", coverage.uncovered.to_string());
+        assert_eq!("2", coverage.duplicated.to_string());
+        assert_eq!(1, coverage.duplicated_work);
+    }
+
+    #[test]
+    fn check_teams() {
+        let teams = parse("2-8,3-7,4-4\n1-3,2-5,3-9\n1-2,3-4,1-4,2-3\n").unwrap();
+        assert_eq!(
+            vec![3, 3, 4],
+            teams.iter().map(|t| t.ranges.len()).collect::<Vec<_>>()
+        );
+
+        assert!(teams[0].contains_all());
+        assert!(!teams[1].contains_all());
+        assert!(teams[2].contains_all());
+
+        assert!(teams[0].pairwise_intersect());
+        assert!(teams[1].pairwise_intersect());
+        assert!(!teams[2].pairwise_intersect());
+
+        assert_eq!(vec![1, 2], teams[0].redundant());
+        assert_eq!(vec![1], teams[1].redundant());
+        assert_eq!(vec![0, 1, 2, 3], teams[2].redundant());
+    }
+
+    #[test]
+    fn check_part1() {
+        assert_eq!(2, part1(&parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn check_part2() {
+        assert_eq!(4, part2(&parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn check_parse_error() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                1,
+                "2-3;4-5",
+                "Expected at least two ranges separated by ','"
+            )),
+            parse("2-4,6-8\n2-3;4-5\n")
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 7, "x", "Invalid number")),
+            parse("2-4,6-x\n")
+        );
+    }
+}
@@ -1,128 +1,32 @@
 //! Day 04: Camp Cleanup
 
-struct Assignment {
-    /// First or left sections
-    pub l: Range,
-    /// Second or right sections
-    pub r: Range,
-}
-
-impl Assignment {
-    pub fn new(l: Range, r: Range) -> Self {
-        Self { l, r }
-    }
-
-    fn covers(&self) -> bool {
-        self.l.contains(&self.r) || self.r.contains(&self.l)
-    }
-
-    fn intersects(&self) -> bool {
-        self.l.intersect(&self.r)
-    }
-}
+use std::process::ExitCode;
 
-struct Range {
-    /// The lower or minimum bound
-    min: u32,
-    /// The upper or maximum bound
-    max: u32,
-}
-
-impl Range {
-    pub fn new(min: u32, max: u32) -> Self {
-        Self { min, max }
-    }
-
-    /// Returns `true` if the given [`Range`] is completely contained
-    pub fn contains(&self, rhs: &Self) -> bool {
-        self.min <= rhs.min && self.max >= rhs.max
-    }
+use aoc_2022_day_04::{coverage, Assignment, Day04, INPUT_PATH};
+use aoc_common::Options;
 
-    /// Returns `true` if both ranges intersect with each other.
-    ///
-    /// Both sections intersect when any bound is inside the bounds of the other.
-    pub fn intersect(&self, rhs: &Self) -> bool {
-        self.max >= rhs.min && rhs.max >= self.min
-    }
+/// The options of day 04.
+#[derive(Debug, clap::Args)]
+struct Day04Options {
+    /// Prints the sections covered by no elf & by several elves
+    #[arg(long)]
+    coverage: bool,
 }
 
-impl From<&str> for Range {
-    fn from(range: &str) -> Self {
-        let (min, max) = range.split_once('-').expect("Failed to parse range.");
-        let min = min.parse::<u32>().expect("Not a number");
-        let max = max.parse::<u32>().expect("Not a number");
-        Range::new(min, max)
+impl Options<Day04> for Day04Options {
+    fn inspect(
+        &self,
+        _text: &str,
+        assignments: &Vec<Assignment>,
+        _config: &(),
+    ) -> anyhow::Result<()> {
+        if self.coverage {
+            println!("{}", coverage(assignments));
+        }
+        Ok(())
     }
 }
 
-/// Returns the total number of overlapping assignments, where one part overlaps completely
-fn part1(sections: &[Assignment]) -> usize {
-    sections
-        .iter()
-        .filter(|&assignment| assignment.covers())
-        .count()
-}
-
-/// Returns the total number of any overlapping assignments
-fn part2(sections: &[Assignment]) -> usize {
-    sections
-        .iter()
-        .filter(|&assignment| assignment.intersects())
-        .count()
-}
-
-fn parse(input: &str) -> Vec<Assignment> {
-    input
-        .lines()
-        .map(str::trim)
-        .filter(|s| !s.is_empty())
-        .filter_map(|s| s.split_once(','))
-        .map(|(l, r)| Assignment::new(l.into(), r.into()))
-        .collect::<Vec<_>>()
-}
-
-fn main() {
-    let input = parse(include_str!("input.txt"));
-    println!("Overlaps: {}", part1(&input));
-    println!("Intersections: {}", part2(&input));
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::*;
-
-    const INPUT: &str = r#"
-        2-4,6-8
-        2-3,4-5
-        5-7,7-9
-        2-8,3-7
-        6-6,4-6
-        2-6,4-8
-    "#;
-
-    #[test]
-    fn check_range_bounds() {
-        let l = Range::new(2, 8);
-        let r = Range::new(3, 7);
-        assert!(l.contains(&r));
-        assert!(!r.contains(&l));
-    }
-
-    #[test]
-    fn check_range_intersects() {
-        let l = Range::new(5, 7);
-        let r = Range::new(7, 9);
-        assert!(l.intersect(&r));
-        assert!(r.intersect(&l));
-    }
-
-    #[test]
-    fn check_part1() {
-        assert_eq!(2, part1(&parse(INPUT)));
-    }
-
-    #[test]
-    fn check_part2() {
-        assert_eq!(4, part2(&parse(INPUT)));
-    }
+fn main() -> ExitCode {
+    aoc_common::run_with::<Day04, Day04Options>(INPUT_PATH)
 }
@@ -0,0 +1,770 @@
+//! Day 05: Supply Stacks
+
+use std::{
+    fmt::{self, Display},
+    io::Write,
+};
+
+use anyhow::anyhow;
+use aoc_common::{
+    solution::{Answer, Solution},
+    ParseError,
+};
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+/// The stacks of crates, the top crate of each stack is the last element.
+#[derive(Debug, Clone)]
+pub struct SupplyStack {
+    stacks: Vec<Vec<char>>,
+}
+
+impl SupplyStack {
+    /// A slightly better handling might have been to put all stacks into a HashMap, due to indices starting with `1`
+    /// Found this quite neat "transpose" implementation, slightly adjusted to skip empty slots
+    pub fn new(num_stacks: usize, rows: &[Vec<Option<char>>]) -> Self {
+        let mut stacks = vec![Vec::with_capacity(rows.len()); num_stacks];
+        // the rows are listed top down, the top crate ends up last
+        for row in rows.iter().rev() {
+            for (stack, c) in stacks.iter_mut().zip(row) {
+                if let Some(c) = c {
+                    stack.push(*c);
+                }
+            }
+        }
+        Self { stacks }
+    }
+
+    /// The crates of all stacks, bottom crate first.
+    pub fn stacks(&self) -> &[Vec<char>] {
+        &self.stacks
+    }
+
+    /// Checks that both stacks of the move exist & the source stack holds enough crates.
+    fn check(&self, mv: &Move) -> anyhow::Result<()> {
+        for number in [mv.from, mv.to] {
+            if number == 0 || number > self.stacks.len() {
+                return Err(anyhow!(
+                    "Stack {} doesn't exist, there are {} stacks",
+                    number,
+                    self.stacks.len()
+                ));
+            }
+        }
+        let stack = &self.stacks[mv.from - 1];
+        if stack.len() < mv.num_crates as usize {
+            return Err(anyhow!(
+                "Cannot take {} crates from stack {} with {} crates",
+                mv.num_crates,
+                mv.from,
+                stack.len()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Takes the pile of crates of the move from the top of the source stack, the top crate
+    /// comes last. The move is checked up front, a failed move leaves the stacks unchanged.
+    fn take(&mut self, mv: &Move) -> anyhow::Result<Vec<char>> {
+        self.check(mv)?;
+        let source = &mut self.stacks[mv.from - 1];
+        Ok(source.split_off(source.len() - mv.num_crates as usize))
+    }
+
+    /// Puts the pile taken by the move onto the target stack in the order of the crane.
+    fn place(&mut self, mv: &Move, pile: &[char], crane: &dyn Crane) {
+        let target = &mut self.stacks[mv.to - 1];
+        // whatever the crane, a pile moved onto the same stack ends up as before
+        if mv.from == mv.to {
+            target.extend_from_slice(pile);
+        } else {
+            target.extend(crane.arrange(pile));
+        }
+    }
+
+    /// Applies the move with the given crane, a failed move leaves the stacks unchanged.
+    pub fn apply(&mut self, mv: &Move, crane: &dyn Crane) -> anyhow::Result<()> {
+        let pile = self.take(mv)?;
+        self.place(mv, &pile, crane);
+        Ok(())
+    }
+
+    /// Applies a single move on the stack, each crate is picked one by one from
+    /// source to target stack, thereby reversing the order of the crates.
+    ///
+    /// "from" and "to" indices start at `1`.
+    pub fn single_move(&mut self, mv: &Move) -> anyhow::Result<()> {
+        self.apply(mv, &CrateMover9000)
+    }
+
+    /// Applies a single move on the stack, the pile of crates are picked at once
+    /// and moved from source to target stack, the order of crates does not change.
+    pub fn multi_move(&mut self, mv: &Move) -> anyhow::Result<()> {
+        self.apply(mv, &CrateMover9001)
+    }
+
+    /// Returns the top crates from the stacks, ignores any empty stacks
+    pub fn top(&self) -> String {
+        self.stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .collect::<String>()
+    }
+}
+
+/// A move applied to the stacks together with the crates it took from the source stack.
+#[derive(Debug, Clone)]
+struct Record {
+    mv: Move,
+    /// The crates taken from the source stack, top crate last
+    taken: Vec<char>,
+}
+
+/// The stacks re-arranged by a crane, the applied moves can be undone & redone.
+///
+/// Only this wrapper keeps the history, solving the puzzle applies the moves on the stacks
+/// directly.
+pub struct History<'a> {
+    stack: SupplyStack,
+    crane: &'a dyn Crane,
+    applied: Vec<Record>,
+    undone: Vec<Record>,
+}
+
+impl<'a> History<'a> {
+    pub fn new(stack: SupplyStack, crane: &'a dyn Crane) -> Self {
+        Self {
+            stack,
+            crane,
+            applied: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+
+    /// The stacks after the applied moves.
+    pub fn stack(&self) -> &SupplyStack {
+        &self.stack
+    }
+
+    /// The applied moves, the first move comes first.
+    pub fn moves(&self) -> impl Iterator<Item = &Move> {
+        self.applied.iter().map(|record| &record.mv)
+    }
+
+    /// Applies the move & drops the moves undone before, a failed move leaves the stacks &
+    /// the history unchanged.
+    pub fn apply(&mut self, mv: &Move) -> anyhow::Result<()> {
+        let taken = self.stack.take(mv)?;
+        self.stack.place(mv, &taken, self.crane);
+        self.applied.push(Record { mv: *mv, taken });
+        self.undone.clear();
+        Ok(())
+    }
+
+    /// Reverts the last applied move, returns the move or `None` when there is nothing to undo.
+    pub fn undo(&mut self) -> Option<Move> {
+        let record = self.applied.pop()?;
+        // only the history changes the stacks, the placed crates are still on top
+        let target = &mut self.stack.stacks[record.mv.to - 1];
+        target.truncate(target.len() - record.taken.len());
+        self.stack.stacks[record.mv.from - 1].extend_from_slice(&record.taken);
+        let mv = record.mv;
+        self.undone.push(record);
+        Some(mv)
+    }
+
+    /// Applies the last undone move again, returns the move or `None` when there is nothing
+    /// to redo.
+    pub fn redo(&mut self) -> Option<Move> {
+        let record = self.undone.pop()?;
+        let source = &mut self.stack.stacks[record.mv.from - 1];
+        source.truncate(source.len() - record.taken.len());
+        self.stack.place(&record.mv, &record.taken, self.crane);
+        let mv = record.mv;
+        self.applied.push(record);
+        Some(mv)
+    }
+}
+
+/// Renders the drawing in the format of the puzzle input, top row first & labels last, it
+/// parses back into the same stacks.
+impl Display for SupplyStack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let height = self.stacks.iter().map(Vec::len).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let row = self
+                .stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<_>>();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        let labels = (1..=self.stacks.len())
+            .map(|label| format!("{:^3}", label))
+            .collect::<Vec<_>>();
+        write!(f, "{}", labels.join(" "))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub num_crates: u32,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Move {
+    pub fn new(num_crates: u32, from: usize, to: usize) -> Self {
+        Self {
+            num_crates,
+            from,
+            to,
+        }
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.num_crates, self.from, self.to
+        )
+    }
+}
+
+/// A crane model, decides in which order the crates of a move land on the target stack.
+pub trait Crane {
+    /// The name of the model
+    fn name(&self) -> String;
+
+    /// Returns the crates as they end up on the target stack, `pile` is taken from the top of
+    /// the source stack, in both the top crate comes last.
+    fn arrange(&self, pile: &[char]) -> Vec<char>;
+}
+
+/// Moves one crate at a time, reverses the order of the crates.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        "CrateMover 9000".to_string()
+    }
+
+    fn arrange(&self, pile: &[char]) -> Vec<char> {
+        pile.iter().rev().copied().collect()
+    }
+}
+
+/// Moves the whole pile at once, keeps the order of the crates.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        "CrateMover 9001".to_string()
+    }
+
+    fn arrange(&self, pile: &[char]) -> Vec<char> {
+        pile.to_vec()
+    }
+}
+
+/// Lifts at most `capacity` crates at a time, each lift keeps the order of its crates.
+///
+/// With a capacity of `1` it works like the [`CrateMover9000`].
+pub struct LimitedCrane {
+    capacity: usize,
+}
+
+impl LimitedCrane {
+    pub fn new(capacity: usize) -> anyhow::Result<Self> {
+        if capacity == 0 {
+            return Err(anyhow!("The crane needs to lift at least one crate"));
+        }
+        Ok(Self { capacity })
+    }
+}
+
+impl Crane for LimitedCrane {
+    fn name(&self) -> String {
+        format!("Crane lifting {} crates", self.capacity)
+    }
+
+    fn arrange(&self, pile: &[char]) -> Vec<char> {
+        // the top lift is moved first & lands at the bottom
+        pile.rchunks(self.capacity).flatten().copied().collect()
+    }
+}
+
+/// The stacks after a move of a replay.
+#[derive(Debug, Clone, Copy)]
+pub struct Step<'a> {
+    /// The index of the move, starts at `0`
+    pub index: usize,
+    pub mv: &'a Move,
+    pub stack: &'a SupplyStack,
+}
+
+impl Display for Step<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "After move #{}: {}", self.index, self.mv)?;
+        write!(f, "{}", self.stack)
+    }
+}
+
+/// Applies the moves one by one, each step borrows the stacks after the move.
+///
+/// The replay ends after the last move, at the stop index or with the first failed move.
+pub struct Replay<'a> {
+    stack: SupplyStack,
+    moves: &'a [Move],
+    crane: &'a dyn Crane,
+    index: usize,
+    stop: usize,
+}
+
+impl<'a> Replay<'a> {
+    pub fn new(stack: SupplyStack, moves: &'a [Move], crane: &'a dyn Crane) -> Self {
+        Self {
+            stack,
+            moves,
+            crane,
+            index: 0,
+            stop: moves.len(),
+        }
+    }
+
+    /// Stops the replay before the move with the given index, `0` applies no moves at all.
+    pub fn stop_at(mut self, index: usize) -> Self {
+        self.stop = index.min(self.moves.len());
+        self
+    }
+
+    /// The stacks after the moves replayed so far.
+    pub fn stack(&self) -> &SupplyStack {
+        &self.stack
+    }
+
+    /// Applies the next move, returns `None` when the replay has ended.
+    pub fn step(&mut self) -> Option<anyhow::Result<Step<'_>>> {
+        if self.index >= self.stop {
+            return None;
+        }
+        let mv = &self.moves[self.index];
+        let index = self.index;
+        if let Err(e) = self.stack.apply(mv, self.crane) {
+            // no further steps after a failed move
+            self.stop = index;
+            return Some(Err(e.context(format!("Move #{}: {}", index, mv))));
+        }
+        self.index += 1;
+        Some(Ok(Step {
+            index,
+            mv,
+            stack: &self.stack,
+        }))
+    }
+}
+
+/// Writes the name of the crane, the initial drawing & the drawing after each step of the
+/// replay, separated by empty lines.
+pub fn export<W: Write>(mut replay: Replay<'_>, writer: &mut W) -> anyhow::Result<()> {
+    writeln!(writer, "Replay with the {}\n", replay.crane.name())?;
+    writeln!(writer, "Initial stacks\n{}", replay.stack())?;
+    while let Some(step) = replay.step() {
+        writeln!(writer, "\n{}", step?)?;
+    }
+    Ok(())
+}
+
+peg::parser! {
+    grammar stack_parser() for str {
+        rule number() -> usize
+            = n:$(['0'..='9']+) {? n.parse::<usize>().or(Err("number")) }
+
+        rule empty_slot() -> Option<char>
+            = "   " { None }
+
+        rule filled_slot() -> Option<char>
+            = "[" c:['A'..='Z'] "]" { Some(c) }
+
+        /// Parses a row of crates "[Z] [M] [P]", empty slots are `None`
+        pub rule crates() -> Vec<Option<char>>
+            = s:((filled_slot() / empty_slot()) ** " ") { s }
+
+        /// Parses the labels below the stacks " 1   2   3 ", labels may have several digits
+        pub rule labels() -> Vec<usize>
+            = " "* l:(number() ++ (" "+)) " "* { l }
+    }
+}
+
+peg::parser! {
+    grammar move_parser() for str {
+        rule number() -> u32
+            = n:$(['0'..='9']+) {? n.parse::<u32>().or(Err("number")) }
+
+        rule stack() -> usize
+            = n:$(['0'..='9']+) {? n.parse::<usize>().or(Err("stack number")) }
+
+        /// Parses the line "move 1 from 2 to 1"
+        pub rule line() -> Move
+            = "move " num:number() " from " f:stack() " to " t:stack() { Move::new(num, f, t) }
+    }
+}
+
+/// Parses the drawing of the stacks, the last line labels the stacks from `1` to the number
+/// of stacks.
+fn parse_drawing<'a>(input: &'a str, drawing: &'a str) -> Result<SupplyStack, ParseError> {
+    let mut lines = drawing.lines().collect::<Vec<_>>();
+    let label_line = lines
+        .pop()
+        .ok_or_else(|| ParseError::at_offset(input, 0, "Expected the drawing of the stacks"))?;
+
+    let labels =
+        stack_parser::labels(label_line).map_err(|e| ParseError::peg(input, label_line, e))?;
+    let unexpected = labels
+        .iter()
+        .enumerate()
+        .find(|&(index, &label)| label != index + 1);
+    if let Some((index, _)) = unexpected {
+        let label = label_line
+            .split_whitespace()
+            .nth(index)
+            .unwrap_or(label_line);
+        return Err(ParseError::at(
+            input,
+            label,
+            format!("Expected stack label {}", index + 1),
+        ));
+    }
+
+    let rows = lines
+        .iter()
+        .map(|&line| {
+            let row = stack_parser::crates(line).map_err(|e| ParseError::peg(input, line, e))?;
+            if row.iter().skip(labels.len()).any(Option::is_some) {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("Expected crates on the {} labelled stacks", labels.len()),
+                ));
+            }
+            Ok(row)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(SupplyStack::new(labels.len(), &rows))
+}
+
+/// Parses a move, both stacks must exist.
+fn parse_move(input: &str, line: &str, num_stacks: usize) -> Result<Move, ParseError> {
+    let mv = move_parser::line(line).map_err(|e| ParseError::peg(input, line, e))?;
+    // the stack numbers are the 4th & 6th word of "move 1 from 2 to 1"
+    for (word, number) in [(3, mv.from), (5, mv.to)] {
+        if number == 0 || number > num_stacks {
+            let text = line.split_whitespace().nth(word).unwrap_or(line);
+            return Err(ParseError::at(
+                input,
+                text,
+                format!("Unknown stack, expected 1 to {}", num_stacks),
+            ));
+        }
+    }
+    Ok(mv)
+}
+
+pub fn parse(input: &str) -> Result<(SupplyStack, Vec<Move>), ParseError> {
+    let (stack, moves) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::at_offset(
+            input,
+            input.len(),
+            "Expected an empty line between stacks & moves",
+        )
+    })?;
+
+    let stack = parse_drawing(input, stack)?;
+    let moves = moves
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_move(input, line, stack.stacks().len()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((stack, moves))
+}
+
+/// Run all moves for crane, re-arrange the stacks
+pub fn part1(mut stack: SupplyStack, moves: &[Move]) -> anyhow::Result<String> {
+    // using `?` operator to return an `Err` works in a `for` loop, but not from a closure
+    for m in moves {
+        stack.single_move(m)?;
+    }
+    Ok(stack.top())
+}
+
+pub fn part2(mut stack: SupplyStack, moves: &[Move]) -> anyhow::Result<String> {
+    for m in moves {
+        stack.multi_move(m)?;
+    }
+    Ok(stack.top())
+}
+
+/// Day 05: Supply Stacks
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Input<'a> = (SupplyStack, Vec<Move>);
+    type Config = ();
+
+    fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part1(input.0.clone(), &input.1)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part2(input.0.clone(), &input.1)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    // This time the formating needs to match the input file
+    const INPUT: &str = r#"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+"#;
+
+    #[test]
+    fn check_stack_parser() -> anyhow::Result<()> {
+        assert_eq!(
+            vec![None, Some('D'), None],
+            stack_parser::crates("    [D]    ")?
+        );
+        assert_eq!(vec![1, 2, 3], stack_parser::labels(" 1   2   3 ")?);
+        assert_eq!(vec![9, 10, 11], stack_parser::labels(" 9  10  11 ")?);
+        Ok(())
+    }
+
+    #[test]
+    fn check_many_stacks() -> anyhow::Result<()> {
+        let labels = (1..=12).map(|n| format!("{:^3}", n)).collect::<Vec<_>>();
+        let input = format!(
+            "{}[Y] [Z]\n{}\n\nmove 1 from 11 to 2\nmove 1 from 12 to 11\n",
+            "    ".repeat(10),
+            labels.join(" ")
+        );
+        let (stack, moves) = parse(&input)?;
+        assert_eq!(12, stack.stacks.len());
+        assert_eq!(Move::new(1, 11, 2), moves[0]);
+        assert_eq!("YZ", part1(stack, &moves)?);
+
+        let input = input.replace("from 12", "from 13");
+        assert_eq!(
+            Err(ParseError::new(
+                5,
+                13,
+                "13",
+                "Unknown stack, expected 1 to 12"
+            )),
+            parse(&input).map(|_| ())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_drawing_errors() {
+        assert_eq!(
+            Err(ParseError::new(2, 6, "3", "Expected stack label 2")),
+            parse("[A] [B]\n 1   3 \n\nmove 1 from 1 to 2\n").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                "[A] [B] [C]",
+                "Expected crates on the 2 labelled stacks"
+            )),
+            parse("[A] [B] [C]\n 1   2 \n\nmove 1 from 1 to 2\n").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn check_move_parser() -> anyhow::Result<()> {
+        assert_eq!(
+            Ok(Move::new(1, 2, 1)),
+            move_parser::line("move 1 from 2 to 1")
+        );
+        assert_eq!(
+            Ok(Move::new(10, 1, 3)),
+            move_parser::line("move 10 from 1 to 3")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_moves() -> anyhow::Result<()> {
+        let (mut stack, _) = parse(INPUT)?;
+        assert_eq!(
+            vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']],
+            stack.stacks
+        );
+        assert_eq!("NDP", stack.top());
+
+        stack.single_move(&Move::new(2, 2, 2))?;
+        assert_eq!("NDP", stack.top());
+        stack.multi_move(&Move::new(2, 2, 3))?;
+        assert_eq!(vec!['P', 'C', 'D'], stack.stacks[2]);
+        assert!(stack.single_move(&Move::new(2, 2, 1)).is_err());
+        assert_eq!(vec!['M'], stack.stacks[1]);
+        Ok(())
+    }
+
+    #[test]
+    fn check_display_round_trip() -> anyhow::Result<()> {
+        let (stack, moves) = parse(INPUT)?;
+        let drawing = stack.to_string();
+        assert_eq!(INPUT.split_once("\n\n").unwrap().0, drawing);
+
+        let moves = moves
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let (parsed, _) = parse(&format!("{}\n\n{}\n", drawing, moves))?;
+        assert_eq!(stack.stacks, parsed.stacks);
+        Ok(())
+    }
+
+    #[test]
+    fn check_replay() -> anyhow::Result<()> {
+        let (stack, moves) = parse(INPUT)?;
+        let mut replay = Replay::new(stack.clone(), &moves, &CrateMover9000);
+        let mut tops = Vec::new();
+        while let Some(step) = replay.step() {
+            tops.push(step?.stack.top());
+        }
+        assert_eq!(vec!["DCP", "CZ", "MZ", "CMZ"], tops);
+
+        let mut replay = Replay::new(stack.clone(), &moves, &CrateMover9001).stop_at(1);
+        assert_eq!(Some(0), replay.step().transpose()?.map(|step| step.index));
+        assert!(replay.step().is_none());
+        assert_eq!("DCP", replay.stack().top());
+
+        let mut output = Vec::new();
+        export(
+            Replay::new(stack, &moves, &CrateMover9000).stop_at(1),
+            &mut output,
+        )?;
+        let expected = [
+            "Replay with the CrateMover 9000",
+            "",
+            "Initial stacks",
+            "    [D]    ",
+            "[N] [C]    ",
+            "[Z] [M] [P]",
+            " 1   2   3 ",
+            "",
+            "After move #0: move 1 from 2 to 1",
+            "[D]        ",
+            "[N] [C]    ",
+            "[Z] [M] [P]",
+            " 1   2   3 ",
+            "",
+        ];
+        assert_eq!(expected.join("\n"), String::from_utf8(output)?);
+        Ok(())
+    }
+
+    #[test]
+    fn check_limited_crane() -> anyhow::Result<()> {
+        let pile = ['A', 'B', 'C', 'D', 'E'];
+        assert_eq!(
+            vec!['D', 'E', 'B', 'C', 'A'],
+            LimitedCrane::new(2)?.arrange(&pile)
+        );
+        assert_eq!(
+            CrateMover9000.arrange(&pile),
+            LimitedCrane::new(1)?.arrange(&pile)
+        );
+        assert_eq!(
+            CrateMover9001.arrange(&pile),
+            LimitedCrane::new(5)?.arrange(&pile)
+        );
+        assert!(LimitedCrane::new(0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_undo_redo() -> anyhow::Result<()> {
+        let (stack, moves) = parse(INPUT)?;
+        let mut history = History::new(stack.clone(), &CrateMover9000);
+        for mv in &moves {
+            history.apply(mv)?;
+        }
+        let last = history.stack().stacks().to_vec();
+        assert_eq!(moves, history.moves().copied().collect::<Vec<_>>());
+        assert_eq!("CMZ", history.stack().top());
+
+        while history.undo().is_some() {}
+        assert_eq!(stack.stacks(), history.stack().stacks());
+        assert_eq!(Some(moves[0]), history.redo());
+        while history.redo().is_some() {}
+        assert_eq!(last, history.stack().stacks());
+
+        // a new move drops the undone moves
+        history.undo();
+        history.apply(&Move::new(1, 1, 2))?;
+        assert_eq!(None, history.redo());
+
+        // the redone move is arranged by the crane of the history
+        let mut history = History::new(stack, &CrateMover9001);
+        history.apply(&Move::new(2, 2, 1))?;
+        let moved = history.stack().stacks().to_vec();
+        history.undo();
+        history.redo();
+        assert_eq!(moved, history.stack().stacks());
+        Ok(())
+    }
+
+    #[test]
+    fn check_failed_move_is_atomic() -> anyhow::Result<()> {
+        let (stack, _) = parse(INPUT)?;
+        let mut history = History::new(stack.clone(), &CrateMover9000);
+        assert!(history.apply(&Move::new(4, 1, 2)).is_err());
+        assert!(history.apply(&Move::new(1, 1, 4)).is_err());
+        assert_eq!(stack.stacks(), history.stack().stacks());
+        assert_eq!(0, history.moves().count());
+        Ok(())
+    }
+
+    #[test]
+    fn check_part1() -> anyhow::Result<()> {
+        let (stack, moves) = parse(INPUT)?;
+        assert_eq!("CMZ", part1(stack, &moves)?);
+        Ok(())
+    }
+
+    #[test]
+    fn check_part2() -> anyhow::Result<()> {
+        let (stack, moves) = parse(INPUT)?;
+        assert_eq!("MCD", part2(stack, &moves)?);
+        Ok(())
+    }
+}
@@ -1,206 +1,53 @@
 //! Day 05: Supply Stacks
 
-use anyhow::anyhow;
-
-#[derive(Debug, Clone)]
-pub struct SupplyStack {
-    pub stacks: Vec<Vec<char>>,
-}
-
-impl SupplyStack {
-    /// A slightly better handling might have been to put all stacks into a HashMap, due to indices starting with `1`
-    /// Found this quite neat "transpose" implementation, slightly adjusted to skip non-alphabetical characters
-    pub fn new(rows: &[Vec<char>]) -> Self {
-        let mut stacks = vec![Vec::with_capacity(rows.len()); rows[0].len()];
-        for row in rows {
-            for (index, c) in row.iter().enumerate() {
-                if c.is_alphabetic() {
-                    stacks[index].push(*c);
-                }
-            }
-        }
-        Self { stacks }
-    }
-
-    /// Applies a single move on the stack, each crate is picked one by one from
-    /// source to target stack, thereby reversing the order of the crates.
-    ///
-    /// "from" and "to" indices start at `1`.
-    pub fn single_move(&mut self, mv: &Move) -> anyhow::Result<()> {
-        // Good example for ranges, classical for loop
-        // Not the fastest way to "move" crates, but it's ok
-        for _ in 0..mv.num_crates {
-            if self.stacks[mv.from - 1].is_empty() {
-                return Err(anyhow!("Cannot take crate from empty stack"));
-            }
-            let c = self.stacks[mv.from - 1].remove(0);
-            self.stacks[mv.to - 1].insert(0, c);
+use std::{fs::File, io, path::PathBuf, process::ExitCode};
+
+use aoc_2022_day_05::{
+    export, Crane, CrateMover9000, CrateMover9001, Day05, Move, Replay, SupplyStack, INPUT_PATH,
+};
+use aoc_common::Options;
+
+/// The options of day 05.
+#[derive(Debug, clap::Args)]
+struct Day05Options {
+    /// Prints the drawing after each move with the crane of the given part
+    #[arg(long, value_name = "PART", value_parser = clap::value_parser!(u8).range(1..=2))]
+    replay: Option<u8>,
+    /// Stops the replay before the move with this index, starts at `0`
+    #[arg(long, value_name = "INDEX", requires = "replay")]
+    stop: Option<usize>,
+    /// Writes the replay to this file instead of stdout
+    #[arg(long, value_name = "PATH", requires = "replay")]
+    export: Option<PathBuf>,
+}
+
+impl Options<Day05> for Day05Options {
+    fn inspect(
+        &self,
+        _text: &str,
+        (stack, moves): &(SupplyStack, Vec<Move>),
+        _config: &(),
+    ) -> anyhow::Result<()> {
+        let crane: &dyn Crane = match self.replay {
+            Some(1) => &CrateMover9000,
+            Some(_) => &CrateMover9001,
+            None => return Ok(()),
+        };
+        let mut replay = Replay::new(stack.clone(), moves, crane);
+        if let Some(stop) = self.stop {
+            replay = replay.stop_at(stop);
         }
-        Ok(())
-    }
-
-    /// Applies a single move on the stack, the pile of crates are picked at once
-    /// and moved from source to target stack, the order of crates does not change.
-    pub fn multi_move(&mut self, mv: &Move) -> anyhow::Result<()> {
-        for index in (0..mv.num_crates as usize).rev() {
-            if self.stacks[mv.from - 1].len() < index {
-                return Err(anyhow!("Stack is smaller than the given index to remove from."));
+        match &self.export {
+            Some(path) => export(replay, &mut io::BufWriter::new(File::create(path)?)),
+            None => {
+                export(replay, &mut io::stdout().lock())?;
+                println!();
+                Ok(())
             }
-            let c = self.stacks[mv.from - 1].remove(index);
-            self.stacks[mv.to - 1].insert(0, c);
         }
-        Ok(())
-    }
-
-    /// Returns the top crates from the stacks, ignores any empty stacks
-    pub fn top(&self) -> String {
-        self.stacks
-            .iter()
-            .filter_map(|stack| stack.first())
-            .collect::<String>()
-    }
-}
-
-#[derive(Debug, PartialEq, Eq)]
-pub struct Move {
-    pub num_crates: u32,
-    pub from: usize,
-    pub to: usize,
-}
-
-impl Move {
-    pub fn new(num_crates: u32, from: usize, to: usize) -> Self {
-        Self {
-            num_crates,
-            from,
-            to,
-        }
-    }
-}
-
-peg::parser! {
-    grammar stack_parser() for str {
-        rule number_slot() -> char
-            = " " n:['0'..='9'] " " { n }
-
-        rule empty_slot() -> char
-            = "   " { '.' }
-
-        rule filled_slot() -> char
-            = "[" c:['A'..='Z'] "]" { c }
-
-        rule slot() -> char
-            = filled_slot() / empty_slot() / number_slot()
-
-        pub rule line() -> Vec<char>
-            = s:(slot() ** " " ) { s }
-    }
-}
-
-peg::parser! {
-    grammar move_parser() for str {
-        rule number() -> u32
-            = n:$(['0'..='9']+) { n.parse::<u32>().unwrap() }
-
-        rule digit() -> usize
-            = n:['0'..='9'] { n.to_string().parse::<usize>().unwrap() }
-
-        /// Parses the line "move 1 from 2 to 1"
-        pub rule line() -> Move
-            = "move " num:number() " from " f:digit() " to " t:digit() { Move::new(num, f, t) }
-    }
-}
-
-fn parse(input: &str) -> anyhow::Result<(SupplyStack, Vec<Move>)> {
-    let (stack, moves) = input
-        .split_once("\n\n")
-        .ok_or_else(|| anyhow!("Failed to split input"))?;
-
-    let stacks = stack
-        .lines()
-        .filter_map(|line| stack_parser::line(line).ok())
-        .collect::<Vec<_>>();
-
-    let stack = SupplyStack::new(&stacks);
-    let moves = moves
-        .lines()
-        .filter_map(|line| move_parser::line(line).ok())
-        .collect::<Vec<_>>();
-
-    Ok((stack, moves))
-}
-
-/// Run all moves for crane, re-arrange the stacks
-fn part1(mut stack: SupplyStack, moves: &[Move]) -> anyhow::Result<String> {
-    // using `?` operator to return an `Err` works in a `for` loop, but not from a closure
-    for m in moves {
-        stack.single_move(m)?;
-    }
-    Ok(stack.top())
-}
-
-fn part2(mut stack: SupplyStack, moves: &[Move]) -> anyhow::Result<String> {
-    for m in moves {
-        stack.multi_move(m)?;
     }
-    Ok(stack.top())
 }
 
-fn main() -> anyhow::Result<()> {
-    let (stack, moves) = parse(include_str!("input.txt"))?;
-    println!("Part 1: {}", part1(stack.clone(), &moves)?);
-    println!("Part 2: {}", part2(stack, &moves)?);
-
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::*;
-
-    // This time the formating needs to match the input file
-    const INPUT: &str = r#"    [D]    
-[N] [C]    
-[Z] [M] [P]
- 1   2   3 
-
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2
-"#;
-
-    #[test]
-    fn check_stack_parser() -> anyhow::Result<()> {
-        assert_eq!(vec!['.', 'D', '.'], stack_parser::line("    [D]    ")?);
-        assert_eq!(vec!['1', '2', '3'], stack_parser::line(" 1   2   3 ")?);
-        Ok(())
-    }
-
-    #[test]
-    fn check_move_parser() -> anyhow::Result<()> {
-        assert_eq!(
-            Ok(Move::new(1, 2, 1)),
-            move_parser::line("move 1 from 2 to 1")
-        );
-        assert_eq!(
-            Ok(Move::new(10, 1, 3)),
-            move_parser::line("move 10 from 1 to 3")
-        );
-        Ok(())
-    }
-
-    #[test]
-    fn check_part1() -> anyhow::Result<()> {
-        let (stack, moves) = parse(INPUT)?;
-        assert_eq!("CMZ", part1(stack, &moves)?);
-        Ok(())
-    }
-
-    #[test]
-    fn check_part2() -> anyhow::Result<()> {
-        let (stack, moves) = parse(INPUT)?;
-        assert_eq!("MCD", part2(stack, &moves)?);
-        Ok(())
-    }
+fn main() -> ExitCode {
+    aoc_common::run_with::<Day05, Day05Options>(INPUT_PATH)
 }
@@ -0,0 +1,90 @@
+//! Day 06: Tuning Trouble
+
+use std::collections::HashSet;
+
+use aoc_common::{
+    solution::{Answer, Solution},
+    ParseError,
+};
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+/// Detects the start of packet marker, a four letter sequence where all letters are different
+///
+/// The first position of such a sequence is returned.
+fn marker_pos(datastream: &str, distinct: usize) -> Option<usize> {
+    let chars = datastream.chars().collect::<Vec<_>>();
+    chars
+        .windows(distinct)
+        .map(|seq| seq.iter().collect::<HashSet<_>>())
+        .position(|seq| seq.len() == distinct)
+        .map(|pos| pos + distinct)
+}
+
+/// Returns the datastream without surrounding whitespace, the datastream is a single line.
+pub fn parse(input: &str) -> Result<&str, ParseError> {
+    let line = input.trim();
+    match line.find(char::is_whitespace) {
+        Some(index) => Err(ParseError::at_offset(
+            input,
+            aoc_common::parse::offset_of(input, line) + index,
+            "Expected a single line datastream",
+        )),
+        None => Ok(line),
+    }
+}
+
+pub fn part1(line: &str) -> usize {
+    marker_pos(line, 4).expect("Failed to find start of packet")
+}
+
+pub fn part2(line: &str) -> usize {
+    marker_pos(line, 14).expect("Failed to find start of packet")
+}
+
+/// Day 06: Tuning Trouble
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+
+    type Input<'a> = &'a str;
+    type Config = ();
+
+    fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn check_part1() {
+        assert_eq!(7, part1("mjqjpqmgbljsphdztnvjfqwrcgsmlb"));
+        assert_eq!(5, part1("bvwbjplbgvbhsrlpgdmjqwftvncz"));
+        assert_eq!(6, part1("nppdvjthqldpwncqszvftbrmjlhg"));
+        assert_eq!(10, part1("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"));
+        assert_eq!(11, part1("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"));
+    }
+
+    #[test]
+    fn check_part2() {
+        assert_eq!(19, part2("mjqjpqmgbljsphdztnvjfqwrcgsmlb"));
+        assert_eq!(23, part2("bvwbjplbgvbhsrlpgdmjqwftvncz"));
+        assert_eq!(23, part2("nppdvjthqldpwncqszvftbrmjlhg"));
+        assert_eq!(29, part2("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"));
+        assert_eq!(26, part2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"));
+    }
+}
@@ -0,0 +1,196 @@
+//! Day 08: Treetop Tree House
+
+use aoc_common::{
+    solution::{Answer, Solution},
+    ParseError,
+};
+use aoc_grid::{Direction, Grid, Point};
+use itertools::Itertools;
+
+use std::fmt::{Display, Formatter};
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+type Pos = Point<i32>;
+
+/// A struct to hold an Iterator used with [`TreeGrid::steps`].
+struct Steps<'a> {
+    grid: &'a TreeGrid,
+    pos: Pos,
+    dir: Direction,
+}
+
+impl<'a> Steps<'a> {
+    pub fn new(grid: &'a TreeGrid, pos: Pos, dir: Direction) -> Self {
+        Self { grid, pos, dir }
+    }
+}
+
+impl<'a> Iterator for Steps<'a> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pos = self.pos.step(self.dir);
+        self.grid.get(self.pos)
+    }
+}
+
+/// Algorithm to scan a line of trees from a given tree height. As soon as we find a tree of same height or
+/// above the remaining trees are hidden and do not count.
+///
+/// Examples:
+/// ```text
+/// scan_trees(5, [3, 5, 3]) -> 2  //
+/// ```
+pub fn scan_trees(tree: u8, iter: impl Iterator<Item = u8>) -> usize {
+    let mut count = 0;
+    for neighbor in iter {
+        count += 1;
+        if neighbor >= tree {
+            break;
+        }
+    }
+    count
+}
+
+#[derive(Debug)]
+pub struct TreeGrid {
+    trees: Grid<u8>,
+}
+
+impl Display for TreeGrid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.trees)
+    }
+}
+
+impl TreeGrid {
+    pub fn new(trees: Grid<u8>) -> Self {
+        Self { trees }
+    }
+
+    pub fn width(&self) -> usize {
+        self.trees.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.trees.height()
+    }
+
+    /// Checks all trees and counts all visible ones by determine all invisible trees first.
+    pub fn visibles(&self) -> usize {
+        self.width() * self.height() - self.invisibles()
+    }
+
+    /// Returns the score of the best scenic tree in the forest
+    pub fn best_scenic(&self) -> Option<usize> {
+        self.trees()
+            .map(|(pos, tree)| {
+                Direction::ORTHOGONAL.iter().fold(1, |product, &dir| {
+                    product * scan_trees(tree, self.steps(pos, dir))
+                })
+            })
+            .max()
+    }
+
+    /// Returns the number of invisible trees that cannot be seen from any side.
+    pub fn invisibles(&self) -> usize {
+        self.trees()
+            .filter(|&(pos, tree)| {
+                Direction::ORTHOGONAL
+                    .iter()
+                    .all(|&dir| self.steps(pos, dir).any(|neighbor| tree <= neighbor))
+            })
+            .count()
+    }
+
+    /// Returns an iterator over all inner trees
+    fn trees(&self) -> impl Iterator<Item = (Pos, u8)> + '_ {
+        (1..self.width() as i32 - 1)
+            .cartesian_product(1..self.height() as i32 - 1)
+            .map(|(x, y)| (Pos::new(x, y), self.get(Pos::new(x, y)).unwrap()))
+    }
+
+    /// Returns a steps iterator from current pos to the given direction (up, left, right, down)
+    fn steps(&self, pos: Pos, dir: Direction) -> Steps<'_> {
+        Steps::new(self, pos, dir)
+    }
+
+    /// Get a tree by index
+    fn get(&self, pos: Pos) -> Option<u8> {
+        self.trees.get(pos).copied()
+    }
+}
+
+pub fn parse(input: &str) -> Result<TreeGrid, ParseError> {
+    let trees = Grid::parse(input, |c| c.to_digit(10).map(|height| height as u8))?;
+    Ok(TreeGrid::new(trees))
+}
+
+/// Returns the number of visible trees
+pub fn part1(grid: &TreeGrid) -> usize {
+    grid.visibles()
+}
+
+/// Returns the score of the best scenic tree
+pub fn part2(grid: &TreeGrid) -> usize {
+    grid.best_scenic()
+        .expect("Failed to find the most scenic tree")
+}
+
+/// Day 08: Treetop Tree House
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+
+    type Input<'a> = TreeGrid;
+    type Config = ();
+
+    fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = r#"
+        30373
+        25512
+        65332
+        33549
+        35390
+    "#;
+
+    #[test]
+    fn evaluate_scenic_iter() {
+        assert_eq!(1, scan_trees(5, [5u8, 2].into_iter()));
+        assert_eq!(1, scan_trees(5, [3u8].into_iter()));
+        assert_eq!(2, scan_trees(5, [1u8, 2].into_iter()));
+        assert_eq!(2, scan_trees(5, [3u8, 5, 3].into_iter()));
+    }
+
+    #[test]
+    fn check_part1() {
+        let tree_grid = parse(INPUT).unwrap();
+        assert_eq!(21, part1(&tree_grid));
+    }
+
+    #[test]
+    fn check_part2() {
+        let tree_grid = parse(INPUT).unwrap();
+        assert_eq!(8, part2(&tree_grid));
+    }
+}
@@ -0,0 +1,212 @@
+//! Day 09: Rope Bridge
+
+use std::collections::HashSet;
+
+use aoc_common::{
+    parse,
+    solution::{Answer, Solution},
+    ParseError,
+};
+use aoc_grid::{Direction, Point};
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+type Pos = Point<i32>;
+
+/// Maps the direction of a move, the y axis points down.
+fn parse_direction(input: &str, d: &str) -> Result<Direction, ParseError> {
+    match d {
+        "R" => Ok(Direction::East),
+        "U" => Ok(Direction::North),
+        "L" => Ok(Direction::West),
+        "D" => Ok(Direction::South),
+        _ => Err(ParseError::at(
+            input,
+            d,
+            "Expected a direction R, U, L or D",
+        )),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Move {
+    pub dir: Direction,
+    pub steps: u32,
+}
+
+impl Move {
+    pub fn new(dir: Direction, steps: u32) -> Self {
+        Self { dir, steps }
+    }
+}
+
+#[derive(Debug)]
+struct Grid {
+    /// The rope consisting of knots
+    rope: Vec<Pos>,
+    /// The list of all visited position by the last tail element (end of rope)
+    trail: HashSet<Pos>,
+    /// The number of knots, length of the rope
+    num_knots: usize,
+}
+
+impl Grid {
+    pub fn new(num_knots: usize) -> Self {
+        // All knots start on position (0, 0)
+        let trail = HashSet::from([Pos::new(0, 0)]);
+        let rope = std::iter::repeat_n(Pos::new(0, 0), num_knots).collect::<Vec<_>>();
+
+        Self {
+            rope,
+            trail,
+            num_knots,
+        }
+    }
+
+    /// Apply the given distance in steps, move head and tail rope, keep track of where the tail stepped.
+    pub fn step(&mut self, m: &Move) {
+        // get the direction to move
+        let dir = m.dir.offset();
+
+        for _ in 0..m.steps {
+            // update the head
+            self.rope[0] += dir;
+
+            // check for all other knots if they need to update their position
+            for index in 1..self.num_knots {
+                let head = self.rope[index - 1];
+                let tail = self.rope[index];
+
+                // move the knot one step towards the previous knot if they are not adjacent
+                if tail.chebyshev(&head) > 1 {
+                    self.rope[index] += (head - tail).signum();
+                }
+            }
+
+            // Add the last knot (end of rope) to trail
+            if let Some(last) = self.rope.last() {
+                self.trail.insert(*last);
+            }
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Move>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|line| {
+            let (dir, steps) = line.split_once(' ').ok_or_else(|| {
+                ParseError::at(
+                    input,
+                    line,
+                    "Expected a direction and steps separated by space",
+                )
+            })?;
+            Ok(Move::new(
+                parse_direction(input, dir)?,
+                parse::number(input, steps)?,
+            ))
+        })
+        .collect()
+}
+
+/// Returns the number of visible trees
+pub fn part1(moves: &[Move]) -> usize {
+    let mut grid = Grid::new(2);
+    for m in moves {
+        grid.step(m);
+    }
+    grid.trail.len()
+}
+
+/// Returns the number of visible trees
+pub fn part2(moves: &[Move]) -> usize {
+    let mut grid = Grid::new(10);
+    for m in moves {
+        grid.step(m);
+    }
+    grid.trail.len()
+}
+
+/// Day 09: Rope Bridge
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Rope Bridge";
+
+    type Input<'a> = Vec<Move>;
+    type Config = ();
+
+    fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = r#"
+        R 4
+        U 4
+        L 3
+        D 1
+        R 4
+        D 1
+        L 5
+        R 2
+    "#;
+
+    #[test]
+    fn check_part1() {
+        let grid = parse(INPUT).unwrap();
+        assert_eq!(13, part1(&grid));
+    }
+
+    #[test]
+    fn check_part2() {
+        let grid = parse(INPUT).unwrap();
+        assert_eq!(1, part2(&grid));
+    }
+
+    #[test]
+    fn check_longer_part2() {
+        let input = r#"
+            R 5
+            U 8
+            L 8
+            D 3
+            R 17
+            D 10
+            L 25
+            U 20
+        "#;
+        let grid = parse(input).unwrap();
+        assert_eq!(36, part2(&grid));
+    }
+
+    #[test]
+    fn check_parse_error() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                1,
+                "X",
+                "Expected a direction R, U, L or D"
+            )),
+            parse("R 4\nX 2\n")
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! Day 12: Hill Climbing Algorithm
+
+use std::fmt::{Display, Formatter};
+
+use aoc_common::{
+    solution::{Answer, Solution},
+    ParseError,
+};
+use aoc_grid::Point;
+use pathfinding::directed::dijkstra;
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+pub type Pos = Point<i32>;
+
+/// 'b', 'b' => true
+/// 'b', 'c' => true
+/// 'b', 'a' => true
+/// 'b', 'd' => false
+fn can_move(l: char, r: char) -> bool {
+    let (l, r) = (l as i32, r as i32);
+    (l - r).abs() <= 1 || l > r
+}
+
+pub struct Grid {
+    start: Pos,
+    end: Pos,
+    cells: aoc_grid::Grid<char>,
+}
+
+impl Grid {
+    /// Creates the height map, replaces the start & end markers with their elevations.
+    pub fn new(mut cells: aoc_grid::Grid<char>, start: Pos, end: Pos) -> Self {
+        *cells.get_mut(start).unwrap() = 'a';
+        *cells.get_mut(end).unwrap() = 'z';
+        Self { start, end, cells }
+    }
+
+    /// This function uses Dijkstra to find the shortest path.
+    pub fn find_shortest_path(&self, start: &Pos) -> Option<(Vec<Pos>, i32)> {
+        dijkstra::dijkstra(
+            start,
+            |&pos| {
+                let height = self.get(pos).expect("Failed to get height");
+                let mut positions = Vec::new();
+
+                for neighbor in self.neighbors(pos).into_iter() {
+                    let dest = self.get(neighbor).expect("Failed to get dest");
+                    if can_move(height, dest) {
+                        positions.push((neighbor, 1));
+                    }
+                }
+
+                positions
+            },
+            |&p| p == self.end,
+        )
+    }
+
+    /// Find the shortest path from all fields that start on 'a' elevation.
+    pub fn find_scenic_path(&self) -> Vec<u32> {
+        self.cells
+            .iter()
+            .filter(|&(_, &c)| c == 'a')
+            .filter_map(|(pos, _)| self.find_shortest_path(&pos))
+            .map(|(_, length)| length as u32)
+            .collect()
+    }
+
+    fn neighbors(&self, pos: Pos) -> Vec<Pos> {
+        self.cells.neighbours4(pos).collect()
+    }
+
+    fn get(&self, pos: Pos) -> Option<char> {
+        self.cells.get(pos).copied()
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+pub fn part1(grid: &Grid) -> usize {
+    let (path, _length) = grid.find_shortest_path(&grid.start).unwrap();
+    path.len() - 1
+}
+
+pub fn part2(grid: &Grid) -> u32 {
+    let result = grid.find_scenic_path();
+    result.iter().min().cloned().expect("Failed to get length")
+}
+
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    let cells = aoc_grid::Grid::parse(input, |c| matches!(c, 'a'..='z' | 'S' | 'E').then_some(c))?;
+
+    let find = |marker: char| {
+        cells.find(|&c| c == marker).ok_or_else(|| {
+            let message = format!("Expected a marker '{}' in the height map", marker);
+            ParseError::at_offset(input, input.len(), message)
+        })
+    };
+    let start = find('S')?;
+    let end = find('E')?;
+
+    Ok(Grid::new(cells, start, end))
+}
+
+/// Day 12: Hill Climbing Algorithm
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hill Climbing Algorithm";
+
+    type Input<'a> = Grid;
+    type Config = ();
+
+    fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"
+        Sabqponm
+        abcryxxl
+        accszExk
+        acctuvwj
+        abdefghi
+    "#;
+
+    #[test]
+    fn check_moves() {
+        assert!(can_move('b', 'b'));
+        assert!(can_move('b', 'c'));
+        assert!(can_move('b', 'a'));
+        assert!(!can_move('b', 'd'));
+    }
+
+    #[test]
+    fn check_part1() {
+        assert_eq!(31, part1(&parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn check_part2() {
+        assert_eq!(29, part2(&parse(INPUT).unwrap()));
+    }
+}
@@ -0,0 +1,168 @@
+//! Day 12: Hill Climbing Algorithm
+
+use std::cmp::Ordering;
+
+use aoc_common::{
+    parse::offset_of,
+    solution::{Answer, Solution},
+    ParseError,
+};
+use nom::{
+    branch::alt, bytes::complete::tag, character::complete::u8, combinator::map,
+    multi::separated_list0, sequence::delimited, IResult,
+};
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+/// Recursive structure to keep (nested) lists & digits
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Entry {
+    List(Vec<Entry>),
+    Int(u8),
+}
+
+impl Ord for Entry {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        match (self, rhs) {
+            (Entry::Int(l), Entry::Int(r)) => l.cmp(r),
+            (Entry::Int(l), Entry::List(r)) => vec![Entry::Int(*l)].cmp(r),
+            (Entry::List(l), Entry::List(r)) => l.cmp(r),
+            (Entry::List(l), Entry::Int(r)) => l.cmp(&vec![Entry::Int(*r)]),
+        }
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+fn parse_entry(input: &str) -> IResult<&str, Entry> {
+    alt((
+        map(
+            delimited(tag("["), separated_list0(tag(","), parse_entry), tag("]")),
+            Entry::List,
+        ),
+        map(u8, Entry::Int),
+    ))(input)
+}
+
+/// Parses a single packet `line`, a sub slice of the puzzle `input`.
+fn parse_packet(input: &str, line: &str) -> Result<Entry, ParseError> {
+    let expected = "Expected a list of numbers & lists";
+    match parse_entry(line) {
+        Ok(("", entry)) => Ok(entry),
+        Ok((rest, _)) => Err(ParseError::at_offset(
+            input,
+            offset_of(input, rest),
+            expected,
+        )),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::at_offset(
+            input,
+            offset_of(input, e.input),
+            expected,
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(input, line, expected)),
+    }
+}
+
+pub fn part1(pairs: &[Entry]) -> usize {
+    pairs
+        .iter()
+        .as_slice()
+        .chunks(2)
+        .enumerate()
+        .filter(|(_index, pair)| pair[0] < pair[1])
+        .map(|(index, _)| index + 1)
+        .sum()
+}
+
+pub fn part2(mut pairs: Vec<Entry>) -> usize {
+    let left = Entry::List(vec![Entry::List(vec![Entry::Int(2)])]);
+    let right = Entry::List(vec![Entry::List(vec![Entry::Int(6)])]);
+
+    pairs.push(left.clone());
+    pairs.push(right.clone());
+    pairs.sort_unstable();
+
+    let first = pairs.iter().position(|p| *p == left).unwrap() + 1;
+    let second = pairs.iter().position(|p| *p == right).unwrap() + 1;
+
+    first * second
+}
+
+pub fn parse(input: &str) -> Result<Vec<Entry>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_packet(input, line))
+        .collect()
+}
+
+/// Day 13: Distress Signal
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Distress Signal";
+
+    type Input<'a> = Vec<Entry>;
+    type Config = ();
+
+    fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part2(input.clone()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("test.txt");
+
+    #[test]
+    fn check_parse_entry() {
+        let (_, entry) = parse_entry("[1,1,3,1,1]").unwrap();
+        assert_eq!(
+            Entry::List(vec![
+                Entry::Int(1),
+                Entry::Int(1),
+                Entry::Int(3),
+                Entry::Int(1),
+                Entry::Int(1)
+            ]),
+            entry
+        );
+        let (_, entry) = parse_entry("[[1],[2,3,4]]").unwrap();
+        assert_eq!(
+            Entry::List(vec![
+                Entry::List(vec![Entry::Int(1)]),
+                Entry::List(vec![Entry::Int(2), Entry::Int(3), Entry::Int(4)]),
+            ]),
+            entry
+        );
+        let (_, entry) = parse_entry("[]").unwrap();
+        assert_eq!(Entry::List(vec![]), entry,);
+    }
+
+    #[test]
+    fn check_part1() {
+        assert_eq!(13, part1(&parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn check_part2() {
+        assert_eq!(140, part2(parse(INPUT).unwrap()));
+    }
+}
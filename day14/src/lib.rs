@@ -0,0 +1,235 @@
+//! Day 14: Regolith Reservoir
+
+use std::collections::BTreeMap;
+
+use aoc_common::{
+    solution::{Answer, Solution},
+    ParseError,
+};
+use aoc_grid::{Direction, Point};
+use nom::{bytes::complete::tag, character::complete::i32, sequence::tuple, IResult};
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Cell {
+    Rock = 0,
+    Sand,
+}
+
+pub type Pos = Point<i32>;
+
+fn parse_pos(input: &str) -> IResult<&str, Pos> {
+    let (input, (x, _, y)) = tuple((i32, tag(","), i32))(input)?;
+    Ok((input, Pos::new(x, y)))
+}
+
+/// Parses the rock path `line`, a sub slice of the puzzle `input`.
+fn parse_line(input: &str, line: &str) -> Result<Vec<Pos>, ParseError> {
+    line.split(" -> ")
+        .map(|point| match parse_pos(point) {
+            Ok(("", pos)) => Ok(pos),
+            _ => Err(ParseError::at(input, point, "Expected a point 'x,y'")),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    cells: BTreeMap<Pos, Cell>,
+    /// The max depth of the lowest rock
+    depth: i32,
+    /// The minimum x
+    min_x: i32,
+    /// The maximum x
+    max_x: i32,
+}
+
+impl Grid {
+    pub fn new(cells: BTreeMap<Pos, Cell>) -> Self {
+        let mut depth = i32::MIN;
+        let mut min_x = i32::MAX;
+        let mut max_x = i32::MIN;
+
+        // get dimensions
+        for (pos, _) in cells.iter() {
+            min_x = i32::min(min_x, pos.x);
+            max_x = i32::max(max_x, pos.x);
+            depth = i32::max(depth, pos.y);
+        }
+
+        Self {
+            cells,
+            depth,
+            min_x,
+            max_x,
+        }
+    }
+
+    pub fn fill_sand(&mut self) -> usize {
+        while self.scan(Pos::new(500, 0)) {}
+
+        // count all the sands
+        self.cells
+            .iter()
+            .filter(|(_pos, &cell)| cell == Cell::Sand)
+            .count()
+    }
+
+    fn scan(&mut self, start: Pos) -> bool {
+        // down, left-down, right-down
+        let directions = [Direction::South, Direction::SouthWest, Direction::SouthEast];
+        let mut sand = start;
+
+        loop {
+            // advance one step in any direction
+            let next_pos = directions
+                .iter()
+                .map(|&dir| sand.step(dir))
+                .find(|&next_pos| !self.cells.contains_key(&next_pos));
+
+            match next_pos {
+                Some(pos) => {
+                    if pos.y >= self.depth {
+                        break;
+                    }
+                    sand = pos;
+                }
+                None => {
+                    self.cells.insert(sand, Cell::Sand);
+                    if sand == start {
+                        return false;
+                    }
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    pub fn build(lines: Vec<Vec<Pos>>) -> Self {
+        let mut cells = BTreeMap::new();
+
+        // mark the grid with blocks
+        for line in lines.iter() {
+            for line in line.windows(2) {
+                if let [l, r] = line {
+                    // vertical line
+                    if l.x == r.x {
+                        let x = l.x;
+                        let y_start = i32::min(l.y, r.y);
+                        let y_end = i32::max(l.y, r.y);
+                        for y in y_start..=y_end {
+                            cells.insert(Pos::new(x, y), Cell::Rock);
+                        }
+                    } else {
+                        // horizontal line
+                        let y = l.y;
+                        let x_start = i32::min(l.x, r.x);
+                        let x_end = i32::max(l.x, r.x);
+                        for x in x_start..=x_end {
+                            cells.insert(Pos::new(x, y), Cell::Rock);
+                        }
+                    }
+                }
+            }
+        }
+
+        Self::new(cells)
+    }
+
+    /// Sets an Air cell to Sand
+    fn set_cell(&mut self, pos: Pos, cell: Cell) {
+        self.cells.insert(pos, cell);
+    }
+}
+
+pub fn part1(mut grid: Grid) -> usize {
+    grid.fill_sand()
+}
+
+pub fn part2(mut grid: Grid) -> usize {
+    // update bounds of grid
+    grid.depth += 2;
+    grid.min_x = 500 - grid.depth - 1;
+    grid.max_x = 500 + grid.depth + 1;
+
+    // add bottom rock row to grid
+    let y = grid.depth;
+    for x in grid.min_x..=grid.max_x {
+        grid.set_cell(Pos::new(x, y), Cell::Rock);
+    }
+
+    grid.fill_sand()
+}
+
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    let lines = input
+        .lines()
+        .map(str::trim)
+        .filter(|&line| !line.is_empty())
+        .map(|line| parse_line(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Grid::build(lines))
+}
+
+/// Day 14: Regolith Reservoir
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Regolith Reservoir";
+
+    type Input<'a> = Grid;
+    type Config = ();
+
+    fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part1(input.clone()).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part2(input.clone()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"
+        498,4 -> 498,6 -> 496,6
+        503,4 -> 502,4 -> 502,9 -> 494,9
+    "#;
+
+    #[test]
+    fn check_parse_lines() {
+        let line = "498,4 -> 498,6 -> 496,6";
+        assert_eq!(Pos::new(498, 4), parse_pos("498,4").unwrap().1);
+        assert_eq!(
+            vec![Pos::new(498, 4), Pos::new(498, 6), Pos::new(496, 6)],
+            parse_line(line, line).unwrap()
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 10, "498;6", "Expected a point 'x,y'")),
+            parse_line("498,4 -> 498;6", "498,4 -> 498;6")
+        );
+    }
+
+    #[test]
+    fn check_part1() {
+        assert_eq!(24, part1(parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn check_part2() {
+        assert_eq!(93, part2(parse(INPUT).unwrap()));
+    }
+}
@@ -0,0 +1,244 @@
+//! Day 15: Beacon Exclusion Zone
+
+use std::collections::HashSet;
+
+use aoc_common::{
+    solution::{Answer, Solution},
+    ParseError,
+};
+use itertools::Itertools;
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+peg::parser! {
+    grammar line_parser() for str {
+        rule number() -> i64
+            = n:$(['-' | '0'..='9']+) {? n.parse::<i64>().or(Err("number")) }
+
+        rule pos() -> Pos
+            = "x=" x:number() ", y=" y:number() { Pos::new(x, y) }
+
+        pub(crate) rule signal() -> Signal
+            = "Sensor at " sensor:pos() ": closest beacon is at " beacon:pos() { Signal::new(sensor, beacon) }
+    }
+}
+
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Hash)]
+pub struct Pos {
+    x: i64,
+    y: i64,
+}
+
+impl Pos {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self, rhs: &Pos) -> i64 {
+        i64::abs(self.x - rhs.x) + i64::abs(self.y - rhs.y)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Range {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Range {
+    pub fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    #[inline]
+    pub fn overlap(&self, rhs: &Range) -> bool {
+        self.end >= rhs.start && rhs.end >= self.start
+    }
+
+    #[inline]
+    pub fn merge(&self, rhs: &Range) -> Range {
+        let start = i64::min(self.start, rhs.start);
+        let end = i64::max(self.end, rhs.end);
+        Range::new(start, end)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Signal {
+    pub sensor: Pos,
+    pub beacon: Pos,
+}
+
+impl Signal {
+    pub fn new(sensor: Pos, beacon: Pos) -> Self {
+        Self { sensor, beacon }
+    }
+
+    pub fn manhattan(&self) -> i64 {
+        self.sensor.manhattan(&self.beacon)
+    }
+}
+
+pub fn part1(signals: Vec<Signal>, line_number: i64) -> usize {
+    // For each signal check if it's signal reaches or crosses the 'y' row
+    // in case it does, calculate the positions on 'y'
+    let beacons = signals
+        .iter()
+        .map(|signal| signal.beacon)
+        .collect::<HashSet<Pos>>();
+
+    let x_positions = signals
+        .iter()
+        .map(|sensor| (sensor.manhattan(), sensor.sensor))
+        .filter(|(distance, sensor)| {
+            let sensor_range = (sensor.y - distance)..(sensor.y + distance);
+            sensor_range.contains(&line_number)
+        })
+        .flat_map(|(max_distance, sensor)| {
+            let distance = (sensor.y - line_number).abs();
+            let max_distance = max_distance - distance;
+
+            (sensor.x - max_distance)..=(sensor.x + max_distance)
+        })
+        .unique()
+        .filter(|x| !beacons.contains(&Pos::new(*x, line_number)));
+
+    x_positions.count()
+}
+
+fn find_missing_beacon(signals: Vec<Signal>, limit: i64) -> Option<Pos> {
+    let signals = signals
+        .iter()
+        .map(|signal| (signal.sensor, signal.manhattan()))
+        .collect::<Vec<_>>();
+
+    // find all the ranges for this row, see if they are contiguous / overlap
+    for line_number in 0..=limit {
+        let mut ranges = signals
+            .iter()
+            .filter(|(sensor, distance)| (sensor.y - line_number).abs() <= *distance)
+            .map(|(sensor, manhattan)| {
+                let dx = (manhattan - (sensor.y - line_number).abs()).abs();
+
+                let minx = i64::max(0, sensor.x - dx);
+                let maxx = i64::min(limit, sensor.x + dx);
+
+                Range::new(minx, maxx)
+            })
+            .collect::<Vec<_>>();
+
+        ranges.sort();
+
+        // check the ranges are contiguous
+        let (_result, x) = ranges
+            .iter()
+            .fold((Range::new(0, 0), None), |mut acc, range| {
+                if acc.0.overlap(range) {
+                    acc.0 = acc.0.merge(range);
+                } else {
+                    acc.1 = Some(acc.0.end + 1);
+                }
+                acc
+            });
+
+        if let Some(x) = x {
+            return Some(Pos::new(x, line_number));
+        }
+    }
+
+    None
+}
+
+/// Find the single position that is not covered by signals, it's the missing beacon.
+pub fn part2(signals: Vec<Signal>, limit: i64) -> usize {
+    let pos = find_missing_beacon(signals, limit).expect("Failed to find missing beacon");
+    (pos.x * 4_000_000 + pos.y) as usize
+}
+
+pub fn parse(input: &str) -> Result<Vec<Signal>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|&line| !line.is_empty())
+        .map(|line| line_parser::signal(line).map_err(|e| ParseError::peg(input, line, e)))
+        .collect()
+}
+
+/// Puzzle parameters, the example uses smaller values than the actual puzzle input.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The row to count the positions without a beacon in part 1
+    pub row: i64,
+    /// The upper limit of both coordinates of the distress beacon in part 2
+    pub limit: i64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            row: 2_000_000,
+            limit: 4_000_000,
+        }
+    }
+}
+
+/// Day 15: Beacon Exclusion Zone
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Beacon Exclusion Zone";
+
+    type Input<'a> = Vec<Signal>;
+    type Config = Config;
+
+    fn parse<'a>(input: &'a str, _config: &Config) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, config: &Config) -> anyhow::Result<Answer> {
+        Ok(part1(input.clone(), config.row).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, config: &Config) -> anyhow::Result<Answer> {
+        Ok(part2(input.clone(), config.limit).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"
+        Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+        Sensor at x=9, y=16: closest beacon is at x=10, y=16
+        Sensor at x=13, y=2: closest beacon is at x=15, y=3
+        Sensor at x=12, y=14: closest beacon is at x=10, y=16
+        Sensor at x=10, y=20: closest beacon is at x=10, y=16
+        Sensor at x=14, y=17: closest beacon is at x=10, y=16
+        Sensor at x=8, y=7: closest beacon is at x=2, y=10
+        Sensor at x=2, y=0: closest beacon is at x=2, y=10
+        Sensor at x=0, y=11: closest beacon is at x=2, y=10
+        Sensor at x=20, y=14: closest beacon is at x=25, y=17
+        Sensor at x=17, y=20: closest beacon is at x=21, y=22
+        Sensor at x=16, y=7: closest beacon is at x=15, y=3
+        Sensor at x=14, y=3: closest beacon is at x=15, y=3
+        Sensor at x=20, y=1: closest beacon is at x=15, y=3
+    "#;
+
+    #[test]
+    fn check_manhattan_distance() {
+        assert_eq!(9, Pos::new(8, 7).manhattan(&Pos::new(2, 10)));
+    }
+
+    #[test]
+    fn check_part1() {
+        assert_eq!(26, part1(parse(INPUT).unwrap(), 10));
+    }
+
+    #[test]
+    fn check_part2() {
+        assert_eq!(56_000_011, part2(parse(INPUT).unwrap(), 20));
+    }
+}
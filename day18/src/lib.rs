@@ -0,0 +1,238 @@
+//! Day 18: Boiling Boulders
+
+use std::{collections::HashSet, ops::Index};
+
+use aoc_common::{
+    parse,
+    solution::{Answer, Solution},
+    ParseError,
+};
+
+use itertools::Itertools;
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub struct Vec3([i32; 3]);
+
+impl Vec3 {
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        Self([x, y, z])
+    }
+
+    fn in_bounds(&self, bounds: &Bounds) -> bool {
+        let (mins, maxs) = (bounds[0], bounds[1]);
+        self[0] >= mins[0] - 1
+            && self[0] <= maxs[0] + 1
+            && self[1] >= mins[1] - 1
+            && self[1] <= maxs[1] + 1
+            && self[2] >= mins[2] - 1
+            && self[2] <= maxs[2] + 1
+    }
+}
+
+impl From<&(i32, i32, i32)> for Vec3 {
+    fn from(&(x, y, z): &(i32, i32, i32)) -> Self {
+        Self([x, y, z])
+    }
+}
+
+impl std::ops::AddAssign for Vec3 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0[0] += rhs[0];
+        self.0[1] += rhs[1];
+        self.0[2] += rhs[2];
+    }
+}
+
+impl std::ops::Add for Vec3 {
+    type Output = Vec3;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vec3([self[0] + rhs[0], self[1] + rhs[1], self[2] + rhs[2]])
+    }
+}
+
+impl Vec3 {
+    const DIRECTIONS: [(i32, i32, i32); 6] = [
+        (0, 0, 1),
+        (0, 0, -1),
+        (0, 1, 0),
+        (0, -1, 0),
+        (1, 0, 0),
+        (-1, 0, 0),
+    ];
+
+    /// Returns the list of neighbors as iterator
+    pub fn neighbors(&self) -> Vec<Vec3> {
+        Self::DIRECTIONS
+            .iter()
+            .map(|&(x, y, z)| *self + Vec3::new(x, y, z))
+            .collect_vec()
+    }
+}
+
+impl Index<usize> for Vec3 {
+    type Output = i32;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+/// Parses the cube `line` 'x,y,z', a sub slice of the puzzle `input`.
+fn parse_cube(input: &str, line: &str) -> Result<Vec3, ParseError> {
+    let (x, y, z) = line
+        .splitn(3, ',')
+        .collect_tuple()
+        .ok_or_else(|| ParseError::at(input, line, "Expected a cube 'x,y,z'"))?;
+
+    Ok(Vec3([
+        parse::number(input, x)?,
+        parse::number(input, y)?,
+        parse::number(input, z)?,
+    ]))
+}
+
+#[derive(Debug)]
+struct Bounds(Vec3, Vec3);
+
+impl Bounds {
+    pub fn new<'a>(cubes: impl Iterator<Item = &'a Vec3>) -> Self {
+        let min = Vec3::new(i32::MAX, i32::MAX, i32::MAX);
+        let max = Vec3::new(i32::MIN, i32::MIN, i32::MIN);
+
+        let (min, max) = cubes.fold((min, max), |(mut min, mut max), cube| {
+            min.0[0] = i32::min(min[0], cube[0]);
+            min.0[1] = i32::min(min[1], cube[1]);
+            min.0[2] = i32::min(min[2], cube[2]);
+            max.0[0] = i32::max(max[0], cube[0]);
+            max.0[1] = i32::max(max[1], cube[1]);
+            max.0[2] = i32::max(max[2], cube[2]);
+            (min, max)
+        });
+        Bounds(min, max)
+    }
+}
+
+impl Index<usize> for Bounds {
+    type Output = Vec3;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.0,
+            1 => &self.1,
+            _ => panic!("Index out of bounds"),
+        }
+    }
+}
+
+/// Using floodfill algorithm: https://en.wikipedia.org/wiki/Flood_fill
+fn find_exposed(cubes: &[Vec3]) -> HashSet<Vec3> {
+    let bounds = Bounds::new(cubes.iter());
+    let start = Vec3::default();
+
+    let mut stack = vec![start];
+    let mut visited = HashSet::new();
+    let mut exposed = HashSet::new();
+
+    visited.insert(start);
+
+    while let Some(pos) = stack.pop() {
+        for neighbor in pos.neighbors() {
+            if cubes.contains(&neighbor) || !neighbor.in_bounds(&bounds) {
+                continue;
+            }
+
+            if visited.insert(neighbor) {
+                stack.push(neighbor);
+                exposed.insert(neighbor);
+            }
+        }
+    }
+
+    exposed
+}
+
+/// Returns the number of sides that are exposed
+pub fn part1(cubes: Vec<Vec3>) -> usize {
+    cubes
+        .iter()
+        .flat_map(|cube| cube.neighbors())
+        .filter(|cube| !cubes.contains(cube))
+        .count()
+}
+
+/// Returns the number of faces exposed, excluding air pockets
+pub fn part2(cubes: Vec<Vec3>) -> usize {
+    let exposed_cubes = find_exposed(&cubes);
+    cubes
+        .iter()
+        .flat_map(|cube| cube.neighbors())
+        .filter(|neighbor| exposed_cubes.contains(neighbor))
+        .count()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec3>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_cube(input, line))
+        .collect()
+}
+
+/// Day 18: Boiling Boulders
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Boiling Boulders";
+
+    type Input<'a> = Vec<Vec3>;
+    type Config = ();
+
+    fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part1(input.clone()).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part2(input.clone()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        2,2,2
+        1,2,2
+        3,2,2
+        2,1,2
+        2,3,2
+        2,2,1
+        2,2,3
+        2,2,4
+        2,2,6
+        1,2,5
+        3,2,5
+        2,1,5
+        2,3,5
+    ";
+
+    #[test]
+    fn check_part1() {
+        assert_eq!(64, part1(parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn check_part2() {
+        assert_eq!(58, part2(parse(INPUT).unwrap()));
+    }
+}
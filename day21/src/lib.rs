@@ -0,0 +1,286 @@
+//! Day 21: Monkey Math
+
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_common::{
+    solution::{Answer, Solution},
+    ParseError,
+};
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+peg::parser! {
+    /// Parses monkey instructions
+    grammar line_parser() for str {
+        rule name() -> String
+            = name:$(['a'..='z']+) { name.to_string() }
+
+        rule number() -> i64
+            = n:$(['0'..='9']+) {? n.parse().or(Err("number")) }
+
+        rule yell() -> Instruction
+            = n:number() { Instruction::Yell(n) }
+
+        rule op() -> Op
+            = "+" { Op::Add }
+            / "*" { Op::Mul }
+            / "/" { Op::Div }
+            / "-" { Op::Sub }
+
+        rule operation() -> Instruction
+            = l:name() " " op:op() " " r:name() { Instruction::operation(op, l, r) }
+
+        rule instruction() -> Instruction
+            = op:(yell() / operation()) { op }
+
+        pub(crate) rule monkey() -> (String, Instruction)
+            = name:name() ": " op:instruction() { (name, op) }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Op {
+    Add,
+    Mul,
+    Div,
+    Sub,
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Op::Add => "+",
+            Op::Mul => "*",
+            Op::Div => "/",
+            Op::Sub => "-",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Instruction {
+    Yell(i64),
+    Operation(String, Op, String),
+}
+
+impl Instruction {
+    pub fn operation(op: Op, left: String, right: String) -> Self {
+        Self::Operation(left, op, right)
+    }
+
+    pub fn evaluate(&self, monkeys: &HashMap<String, Instruction>) -> i64 {
+        match self {
+            Instruction::Yell(n) => *n,
+            Instruction::Operation(left, op, right) => {
+                let (left, right) = (&monkeys[left], &monkeys[right]);
+
+                match op {
+                    Op::Add => left.evaluate(monkeys) + right.evaluate(monkeys),
+                    Op::Mul => left.evaluate(monkeys) * right.evaluate(monkeys),
+                    Op::Div => left.evaluate(monkeys) / right.evaluate(monkeys),
+                    Op::Sub => left.evaluate(monkeys) - right.evaluate(monkeys),
+                }
+            }
+        }
+    }
+}
+
+/// Graph traversal function
+fn traverse(name: &str, value: i64, monkeys: &HashMap<String, Instruction>) -> i64 {
+    if name == "humn" {
+        return value;
+    }
+
+    match &monkeys[name] {
+        Instruction::Yell(n) => *n,
+        Instruction::Operation(left, op, right) => {
+            let (next_monkey, new_value) = if is_human_branch(left, monkeys) {
+                // invert calculation for human branch
+                let right_total = monkeys[right].evaluate(monkeys);
+                let new_value = match op {
+                    Op::Add => value - right_total,
+                    Op::Mul => value / right_total,
+                    Op::Div => value * right_total,
+                    Op::Sub => value + right_total,
+                };
+                (left, new_value)
+            } else {
+                // normal evalulation
+                let left_total = monkeys[left].evaluate(monkeys);
+                let new_value = match op {
+                    Op::Add => value - left_total,
+                    Op::Sub => left_total - value,
+                    Op::Mul => value / left_total,
+                    Op::Div => left_total / value,
+                };
+                (right, new_value)
+            };
+
+            traverse(next_monkey, new_value, monkeys)
+        }
+    }
+}
+
+/// Helper function to check if this node is part of the "human" branch
+fn is_human_branch(name: &str, monkeys: &HashMap<String, Instruction>) -> bool {
+    if name == "humn" {
+        return true;
+    }
+    match &monkeys[name] {
+        Instruction::Yell(_) => false,
+        Instruction::Operation(left, _, right) => {
+            is_human_branch(left, monkeys) || is_human_branch(right, monkeys)
+        }
+    }
+}
+
+pub fn part1(monkeys: &HashMap<String, Instruction>) -> i64 {
+    monkeys["root"].evaluate(monkeys)
+}
+
+/// Find chain of instructions from root to "humn", determine the value of the other
+/// branch in root, then invert all instructions down (with value) to "humn", then evaluate
+/// ignore sub-branch below "humn"
+pub fn part2(monkeys: &HashMap<String, Instruction>) -> i64 {
+    if let Instruction::Operation(left, _, right) = &monkeys["root"] {
+        // check which branch needs to get calculated for root
+        let (name, root_total) = if is_human_branch(left, monkeys) {
+            (left, monkeys[right].evaluate(monkeys))
+        } else {
+            (right, monkeys[left].evaluate(monkeys))
+        };
+
+        traverse(name, root_total, monkeys)
+    } else {
+        panic!("Root is not an infix operation.");
+    }
+}
+
+/// Parses the string, returns a map of monkey id to operation
+///
+/// The monkey `root` & all monkeys of the operations must be defined.
+pub fn parse(input: &str) -> Result<HashMap<String, Instruction>, ParseError> {
+    let lines = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+    let monkeys = lines
+        .iter()
+        .map(|line| line_parser::monkey(line).map_err(|e| ParseError::peg(input, line, e)))
+        .collect::<Result<HashMap<_, _>, _>>()?;
+
+    if !monkeys.contains_key("root") {
+        return Err(ParseError::at_offset(
+            input,
+            input.len(),
+            "Expected the monkey root",
+        ));
+    }
+    for line in lines {
+        // "name: left op right", the operands are the 2nd & 4th word
+        let mut words = line.split(' ');
+        let operands = [words.nth(1), words.nth(1)];
+        let unknown = operands.into_iter().flatten().find(|name| {
+            name.starts_with(|c: char| c.is_ascii_lowercase()) && !monkeys.contains_key(*name)
+        });
+        if let Some(name) = unknown {
+            return Err(ParseError::at(input, name, "Unknown monkey"));
+        }
+    }
+    Ok(monkeys)
+}
+
+/// Day 21: Monkey Math
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Monkey Math";
+
+    type Input<'a> = HashMap<String, Instruction>;
+    type Config = ();
+
+    fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        root: pppw + sjmn
+        dbpl: 5
+        cczh: sllz + lgvd
+        zczc: 2
+        ptdq: humn - dvpt
+        dvpt: 3
+        lfqf: 4
+        humn: 5
+        ljgn: 2
+        sjmn: drzm * dbpl
+        sllz: 4
+        pppw: cczh / lfqf
+        lgvd: ljgn * ptdq
+        drzm: hmdt - zczc
+        hmdt: 32
+    ";
+
+    #[test]
+    fn check_parser() {
+        assert_eq!(
+            Ok((
+                "root".into(),
+                Instruction::Operation("pppw".into(), Op::Add, "sjmn".into())
+            )),
+            line_parser::monkey("root: pppw + sjmn"),
+        );
+    }
+
+    #[test]
+    fn check_unknown_monkeys() {
+        assert_eq!(
+            Err(ParseError::new(2, 14, "abcd", "Unknown monkey")),
+            parse("humn: 5\nroot: humn + abcd\n")
+        );
+        assert_eq!(
+            Err(ParseError::new(2, 1, "", "Expected the monkey root")),
+            parse("humn: 5\n")
+        );
+    }
+
+    #[test]
+    fn check_part1() {
+        assert_eq!(152, part1(&parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn check_part2() {
+        assert_eq!(301, part2(&parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn check_parse_error() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                12,
+                "%",
+                "Expected one of \"*\", \"+\", \"-\", \"/\""
+            )),
+            parse("dbpl: 5\nroot: pppw % sjmn\n")
+        );
+    }
+}
@@ -0,0 +1,585 @@
+//! Day 22: Monkey Map
+
+use std::fmt::{Display, Formatter};
+
+use aoc_common::{
+    solution::{Answer, Solution},
+    ParseError,
+};
+use aoc_grid::{Direction, Grid, Point};
+use itertools::Itertools;
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MoveInput {
+    Left,
+    Right,
+    Steps(u32),
+}
+
+peg::parser! {
+    /// Parses line of movement Instructions
+    grammar line_parser() for str {
+        rule steps() -> MoveInput
+            = n:$(['0'..='9']+) {? n.parse::<u32>().map(MoveInput::Steps).or(Err("number")) }
+
+        rule right() -> MoveInput
+            = "R" { MoveInput::Right }
+
+        rule left() -> MoveInput
+            = "L" { MoveInput::Left }
+
+        pub(crate) rule line() -> Vec<MoveInput>
+            = items:((left() / right() / steps()) ** "") { items }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Tile {
+    Empty,
+    Floor,
+    Wall,
+}
+
+impl Tile {
+    /// Parses the tile, `None` for an unknown character.
+    pub fn parse(c: char) -> Option<Self> {
+        match c {
+            ' ' => Some(Tile::Empty),
+            '.' => Some(Tile::Floor),
+            '#' => Some(Tile::Wall),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Tile::Empty => " ",
+            Tile::Floor => ".",
+            Tile::Wall => "#",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Dir {
+    Right = 0,
+    Down,
+    Left,
+    Up,
+}
+
+impl From<u8> for Dir {
+    fn from(v: u8) -> Self {
+        match v {
+            0 => Self::Right,
+            1 => Self::Down,
+            2 => Self::Left,
+            3 => Self::Up,
+            _ => panic!("Unsupported direction"),
+        }
+    }
+}
+
+impl Dir {
+    pub fn left(&self) -> Self {
+        ((*self as u8 + 3) % 4).into()
+    }
+
+    pub fn right(&self) -> Self {
+        ((*self as u8 + 1) % 4).into()
+    }
+
+    pub fn direction(&self) -> Pos {
+        let dir = match self {
+            Dir::Right => Direction::East,
+            Dir::Down => Direction::South,
+            Dir::Left => Direction::West,
+            Dir::Up => Direction::North,
+        };
+        dir.offset()
+    }
+}
+
+pub type Pos = Point<i32>;
+
+/// A trait to wrap moves in different ways
+pub trait BoardWrapper {
+    fn advance(&self, board: &Board, pos: Pos, dir: Dir) -> (Pos, Dir);
+}
+
+/// Wraps around on a 2-dimensional plane.
+struct Planar {}
+
+impl BoardWrapper for Planar {
+    fn advance(&self, board: &Board, pos: Pos, dir: Dir) -> (Pos, Dir) {
+        let pos = pos + dir.direction();
+        (
+            Pos::new(
+                pos.x.rem_euclid(board.width()),
+                pos.y.rem_euclid(board.height()),
+            ),
+            dir,
+        )
+    }
+}
+
+/// Maps the cube side & direction when leaving a side to the next cube side & direction.
+type CubeWrap = fn(Pos, i32, Dir) -> (i32, i32, Dir);
+
+/// Wraps around on a cuboid
+struct Cuboid {
+    wrap: CubeWrap,
+}
+
+impl Cuboid {
+    /// The cube sides (x, y) of the example layout, see [`wrap_example_cube_side`].
+    const EXAMPLE_SIDES: [(i32, i32); 6] = [(2, 0), (0, 1), (1, 1), (2, 1), (2, 2), (3, 2)];
+
+    /// Picks the side transitions that match the layout of the cube sides on the board.
+    pub fn new(board: &Board) -> Self {
+        let wrap: CubeWrap = if board.cube_sides() == Self::EXAMPLE_SIDES {
+            wrap_example_cube_side
+        } else {
+            wrap_cube_side
+        };
+        Self { wrap }
+    }
+}
+
+/// This cuboid wrapper is very specific to the given input file and may not work
+/// for other cube side layouts in the input file.
+///
+/// The layout in the input file is:
+///
+/// ```text
+///        ┌─────┬─────┐   : Consider the following sides of the cube
+///        │     │     │   :             (x, y)
+///        │  1  │  2  │   : 1 - back    (1, 0)
+///        │     │     │   : 2 - right   (2, 0)
+///        ├─────┼─────┘   : 3 - top     (1, 1)
+///        │     │         : 4 - left    (0, 2)
+///        │  3  │         : 5 - front   (1, 2)
+///        │     │         : 6 - bottom  (0, 3)
+///  ┌─────┼─────┤
+///  │     │     │
+///  │  4  │  5  │
+///  │     │     │
+///  ├─────┼─────┘
+///  │     │
+///  │  6  │
+///  │     │
+///  └─────┘
+/// ```
+///
+fn wrap_cube_side(pos: Pos, side: i32, dir: Dir) -> (i32, i32, Dir) {
+    let (cube_col, cube_row, new_dir) = match (pos.x / side, pos.y / side, dir) {
+        (1, 0, Dir::Up) => (0, 3, Dir::Right),
+        (1, 0, Dir::Right) => (2, 0, Dir::Right),
+        (1, 0, Dir::Down) => (1, 1, Dir::Down),
+        (1, 0, Dir::Left) => (0, 2, Dir::Right),
+        (2, 0, Dir::Up) => (0, 3, Dir::Up),
+        (2, 0, Dir::Right) => (1, 2, Dir::Left),
+        (2, 0, Dir::Down) => (1, 1, Dir::Left),
+        (2, 0, Dir::Left) => (1, 0, Dir::Left),
+        (1, 1, Dir::Up) => (1, 0, Dir::Up),
+        (1, 1, Dir::Right) => (2, 0, Dir::Up),
+        (1, 1, Dir::Down) => (1, 2, Dir::Down),
+        (1, 1, Dir::Left) => (0, 2, Dir::Down),
+        (0, 2, Dir::Up) => (1, 1, Dir::Right),
+        (0, 2, Dir::Right) => (1, 2, Dir::Right),
+        (0, 2, Dir::Down) => (0, 3, Dir::Down),
+        (0, 2, Dir::Left) => (1, 0, Dir::Right),
+        (1, 2, Dir::Up) => (1, 1, Dir::Up),
+        (1, 2, Dir::Right) => (2, 0, Dir::Left),
+        (1, 2, Dir::Down) => (0, 3, Dir::Left),
+        (1, 2, Dir::Left) => (0, 2, Dir::Left),
+        (0, 3, Dir::Up) => (0, 2, Dir::Up),
+        (0, 3, Dir::Right) => (1, 2, Dir::Up),
+        (0, 3, Dir::Down) => (2, 0, Dir::Down),
+        (0, 3, Dir::Left) => (1, 0, Dir::Down),
+        _ => unreachable!("Unsupported movement"),
+    };
+    (cube_col, cube_row, new_dir)
+}
+
+/// The cube side transitions of the example layout.
+///
+/// ```text
+///              ┌─────┐         : Consider the following sides of the cube
+///              │     │         :             (x, y)
+///              │  1  │         : 1 - top     (2, 0)
+///              │     │         : 2 - back    (0, 1)
+///  ┌─────┬─────┼─────┤         : 3 - left    (1, 1)
+///  │     │     │     │         : 4 - front   (2, 1)
+///  │  2  │  3  │  4  │         : 5 - bottom  (2, 2)
+///  │     │     │     │         : 6 - right   (3, 2)
+///  └─────┴─────┼─────┼─────┐
+///              │     │     │
+///              │  5  │  6  │
+///              │     │     │
+///              └─────┴─────┘
+/// ```
+///
+fn wrap_example_cube_side(pos: Pos, side: i32, dir: Dir) -> (i32, i32, Dir) {
+    match (pos.x / side, pos.y / side, dir) {
+        (2, 0, Dir::Up) => (0, 1, Dir::Down),
+        (2, 0, Dir::Right) => (3, 2, Dir::Left),
+        (2, 0, Dir::Down) => (2, 1, Dir::Down),
+        (2, 0, Dir::Left) => (1, 1, Dir::Down),
+        (0, 1, Dir::Up) => (2, 0, Dir::Down),
+        (0, 1, Dir::Right) => (1, 1, Dir::Right),
+        (0, 1, Dir::Down) => (2, 2, Dir::Up),
+        (0, 1, Dir::Left) => (3, 2, Dir::Up),
+        (1, 1, Dir::Up) => (2, 0, Dir::Right),
+        (1, 1, Dir::Right) => (2, 1, Dir::Right),
+        (1, 1, Dir::Down) => (2, 2, Dir::Right),
+        (1, 1, Dir::Left) => (0, 1, Dir::Left),
+        (2, 1, Dir::Up) => (2, 0, Dir::Up),
+        (2, 1, Dir::Right) => (3, 2, Dir::Down),
+        (2, 1, Dir::Down) => (2, 2, Dir::Down),
+        (2, 1, Dir::Left) => (1, 1, Dir::Left),
+        (2, 2, Dir::Up) => (2, 1, Dir::Up),
+        (2, 2, Dir::Right) => (3, 2, Dir::Right),
+        (2, 2, Dir::Down) => (0, 1, Dir::Up),
+        (2, 2, Dir::Left) => (1, 1, Dir::Up),
+        (3, 2, Dir::Up) => (2, 1, Dir::Left),
+        (3, 2, Dir::Right) => (2, 0, Dir::Left),
+        (3, 2, Dir::Down) => (0, 1, Dir::Right),
+        (3, 2, Dir::Left) => (2, 2, Dir::Left),
+        _ => unreachable!("Unsupported movement"),
+    }
+}
+
+/// Compare with: https://github.com/wilkotom/AoC2022/blob/main/day22/src/main.rs
+/// & https://nickymeuleman.netlify.app/garden/aoc2022-day22
+impl BoardWrapper for Cuboid {
+    fn advance(&self, board: &Board, current_pos: Pos, dir: Dir) -> (Pos, Dir) {
+        let side = board.side;
+
+        // Get the current cube side ranges to check of current pos
+        let (current_side_x, current_side_y) = (current_pos.x / side, current_pos.y / side);
+        let current_cube_x = current_side_x * side..current_side_x * side + side;
+        let current_cube_y = current_side_y * side..current_side_y * side + side;
+
+        // Calculate which cube side (& dir) to transition to in case.
+        // Advance one step, then wrap around if the new position is not within the same cube side
+        let next_pos = current_pos + dir.direction();
+
+        if current_cube_x.contains(&next_pos.x) && current_cube_y.contains(&next_pos.y) {
+            (next_pos, dir)
+        } else {
+            tracing::trace!(
+                target: "aoc_2022_day_22::cuboid",
+                "Wrap, current: {} ({:?}) at ({},{}), next: {}",
+                current_pos,
+                dir,
+                current_side_x,
+                current_side_y,
+                next_pos
+            );
+
+            // Determine next cube and the offset within the cube
+            let (cube_col, cube_row, new_dir) = (self.wrap)(current_pos, side, dir);
+            let (cube_x, cube_y) = (next_pos.x % side, next_pos.y % side);
+            tracing::trace!(
+                target: "aoc_2022_day_22::cuboid",
+                "New cube: {},{}, cube offset: {},{}, dir: {:?}, new_dir: {:?}",
+                cube_col,
+                cube_row,
+                cube_x,
+                cube_y,
+                dir,
+                new_dir
+            );
+
+            let last = side - 1;
+            let i = match dir {
+                Dir::Left => last - cube_y,
+                Dir::Right => cube_y,
+                Dir::Up => cube_x,
+                Dir::Down => last - cube_x,
+            };
+
+            // find new idxes within the cube
+            let new_row = match new_dir {
+                Dir::Left => last - i,
+                Dir::Right => i,
+                Dir::Up => last,
+                Dir::Down => 0,
+            };
+            let new_col = match new_dir {
+                Dir::Left => last,
+                Dir::Right => 0,
+                Dir::Up => i,
+                Dir::Down => last - i,
+            };
+
+            let new_pos = Pos::new(cube_col * side + new_col, cube_row * side + new_row);
+            tracing::trace!(
+                target: "aoc_2022_day_22::cuboid",
+                "New pos: {}, new_col: {}, new_row: {}",
+                new_pos,
+                new_col,
+                new_row
+            );
+            (new_pos, new_dir)
+        }
+    }
+}
+
+/// Two dimensional board with wall / floor tiles
+pub struct Board {
+    side: i32,
+    tiles: Grid<Tile>,
+}
+
+impl Board {
+    pub fn new(tiles: Grid<Tile>, side: i32) -> Self {
+        Board { side, tiles }
+    }
+
+    fn width(&self) -> i32 {
+        self.tiles.width() as i32
+    }
+
+    fn height(&self) -> i32 {
+        self.tiles.height() as i32
+    }
+
+    /// Apply the given moves on the board, starting from most top left floor tile
+    ///
+    /// Returns the final position.
+    fn apply(&self, moves: &[MoveInput], wrapper: &impl BoardWrapper) -> (Pos, Dir) {
+        moves
+            .iter()
+            .fold((self.start_pos(), Dir::Right), |(pos, dir), m| {
+                self.next_move(pos, dir, m, wrapper)
+            })
+    }
+
+    /// Advance next move, either turn left or right or move forward a number of steps until
+    /// a free spot is reached or a wall is hit.
+    fn next_move(
+        &self,
+        start: Pos,
+        dir: Dir,
+        move_input: &MoveInput,
+        wrapper: &impl BoardWrapper,
+    ) -> (Pos, Dir) {
+        tracing::trace!(
+            target: "aoc_2022_day_22::board",
+            "Next move: {} ({:?}), move: {:?}",
+            start,
+            dir,
+            move_input,
+        );
+
+        let mut pos = start;
+        let mut dir = dir;
+
+        match move_input {
+            MoveInput::Left => dir = dir.left(),
+            MoveInput::Right => dir = dir.right(),
+            MoveInput::Steps(num_steps) => {
+                let mut next_pos = start;
+                let mut next_dir = dir;
+                let mut steps = 0;
+
+                loop {
+                    // advance to next pos, wrap around if necessary, the direction only
+                    // changes when the next tile can be entered.
+                    (next_pos, next_dir) = wrapper.advance(self, next_pos, next_dir);
+                    let tile = self.get_tile(next_pos);
+
+                    // get to the next tile
+                    match tile {
+                        Tile::Empty => {
+                            continue;
+                        }
+                        Tile::Floor => {
+                            pos = next_pos;
+                            dir = next_dir;
+                            steps += 1;
+                            if steps == *num_steps {
+                                break;
+                            }
+                        }
+                        Tile::Wall => {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        (pos, dir)
+    }
+
+    /// Returns the positions (x, y) of all cube sides on the board, row by row.
+    fn cube_sides(&self) -> Vec<(i32, i32)> {
+        (0..self.height() / self.side)
+            .cartesian_product(0..self.width() / self.side)
+            .map(|(y, x)| (x, y))
+            .filter(|&(x, y)| self.get_tile(Pos::new(x * self.side, y * self.side)) != Tile::Empty)
+            .collect()
+    }
+
+    /// Returns the start pos, most top left floor tile.
+    fn start_pos(&self) -> Pos {
+        self.tiles.find(|&t| t == Tile::Floor).unwrap()
+    }
+
+    /// Returns the tile with the given position.
+    fn get_tile(&self, pos: Pos) -> Tile {
+        self.tiles.get(pos).copied().unwrap_or(Tile::Empty)
+    }
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.tiles)
+    }
+}
+
+pub fn part1(board: &Board, moves: &[MoveInput]) -> i64 {
+    let (pos, dir) = board.apply(moves, &Planar {});
+    ((pos.y + 1) * 1000 + (pos.x + 1) * 4 + dir as i32) as i64
+}
+
+pub fn part2(board: &Board, moves: &[MoveInput]) -> i64 {
+    tracing::debug!(start = %board.start_pos(), "Walking the cube");
+
+    let (pos, dir) = board.apply(moves, &Cuboid::new(board));
+    ((pos.y + 1) * 1000 + (pos.x + 1) * 4 + dir as i32) as i64
+}
+
+/// Parses the string, returns a map of monkey id to operation
+pub fn parse(input: &str, side: i32) -> Result<(Board, Vec<MoveInput>), ParseError> {
+    let (board, instructions) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::at_offset(
+            input,
+            input.len(),
+            "Expected an empty line between map & path",
+        )
+    })?;
+
+    let rows = board
+        .lines()
+        .map(|line| {
+            line.char_indices()
+                .map(|(index, c)| {
+                    Tile::parse(c).ok_or_else(|| {
+                        let text = &line[index..index + c.len_utf8()];
+                        ParseError::at(input, text, "Expected a tile ' ', '.' or '#'")
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let board = Board::new(Grid::from_rows(rows, Tile::Empty), side);
+
+    let instructions = instructions.trim();
+    let moves =
+        line_parser::line(instructions).map_err(|e| ParseError::peg(input, instructions, e))?;
+
+    Ok((board, moves))
+}
+
+/// Puzzle parameters, the example uses a smaller board than the actual puzzle input.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The side length of a single cube side
+    pub side: i32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { side: 50 }
+    }
+}
+
+/// Day 22: Monkey Map
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Monkey Map";
+
+    type Input<'a> = (Board, Vec<MoveInput>);
+    type Config = Config;
+
+    fn parse<'a>(input: &'a str, config: &Config) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input, config.side)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &Config) -> anyhow::Result<Answer> {
+        Ok(part1(&input.0, &input.1).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &Config) -> anyhow::Result<Answer> {
+        Ok(part2(&input.0, &input.1).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("test.txt");
+
+    #[test]
+    fn check_parse_moves() {
+        let (_board, moves) = parse(INPUT, 4).unwrap();
+        assert_eq!(
+            vec![
+                MoveInput::Steps(10),
+                MoveInput::Right,
+                MoveInput::Steps(5),
+                MoveInput::Left,
+                MoveInput::Steps(5),
+                MoveInput::Right,
+                MoveInput::Steps(10),
+                MoveInput::Left,
+                MoveInput::Steps(4),
+                MoveInput::Right,
+                MoveInput::Steps(5),
+                MoveInput::Left,
+                MoveInput::Steps(5),
+            ],
+            moves,
+        )
+    }
+
+    #[test]
+    fn check_part1() {
+        let (board, moves) = parse(INPUT, 4).unwrap();
+        assert_eq!(6032, part1(&board, &moves));
+    }
+
+    #[test]
+    fn check_part2() {
+        let (board, moves) = parse(INPUT, 4).unwrap();
+        assert_eq!(5031, part2(&board, &moves));
+    }
+
+    #[test]
+    fn check_parse_error() {
+        assert_eq!(
+            Some(ParseError::new(
+                2,
+                4,
+                "x",
+                "Expected a tile ' ', '.' or '#'"
+            )),
+            parse("  ..\n  .x\n\n10R5\n", 4).err()
+        );
+    }
+}
@@ -0,0 +1,334 @@
+//! Day 23: Unstable Diffusion
+
+use std::{
+    cmp::{max, min},
+    collections::{HashMap, HashSet},
+    fmt::{Display, Formatter},
+};
+
+use aoc_common::{
+    solution::{Answer, Solution},
+    ParseError,
+};
+use aoc_grid::{Direction, Point};
+use itertools::Itertools;
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+pub type Pos = Point<i32>;
+
+/// Returns true if this direction contains no neighbors.
+///
+/// The neighbors are ordered as [`Direction::ALL`], clockwise starting north.
+fn propose(dir: Direction, neighbors: &[bool; 8]) -> bool {
+    let [n, ne, e, se, s, sw, w, nw] = neighbors;
+
+    match dir {
+        Direction::North => !n && !ne && !nw,
+        Direction::South => !s && !se && !sw,
+        Direction::West => !w && !nw && !sw,
+        Direction::East => !e && !ne && !se,
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    elves: HashSet<Pos>,
+}
+
+impl Grid {
+    pub fn new(elves: HashSet<Pos>) -> Self {
+        Self { elves }
+    }
+
+    /// Returns the bounds as two coordinate pairs:
+    /// upper left & lower right.
+    pub fn bounds(&self) -> (Pos, Pos) {
+        let mut minx = i32::MAX;
+        let mut miny = i32::MAX;
+        let mut maxx = i32::MIN;
+        let mut maxy = i32::MIN;
+
+        for elf in self.elves.iter() {
+            minx = min(elf.x, minx);
+            miny = min(elf.y, miny);
+            maxx = max(elf.x, maxx);
+            maxy = max(elf.y, maxy);
+        }
+
+        (Pos::new(minx, miny), Pos::new(maxx, maxy))
+    }
+
+    pub fn neighbors(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        pos.neighbours8()
+    }
+
+    /// Determines the smallest rectangle where all elves fit in, return number of free tiles.
+    pub fn empty_tiles(&self) -> i64 {
+        let (min, max) = self.bounds();
+        let width = (max.x - min.x) as i64 + 1;
+        let height = (max.y - min.y) as i64 + 1;
+        width * height - self.elves.len() as i64
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (Pos { x: minx, y: miny }, Pos { x: maxx, y: maxy }) = self.bounds();
+
+        for y in miny..=maxy {
+            for x in minx..=maxx {
+                if self.elves.contains(&Pos::new(x, y)) {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Advances all elves across the grid once!
+///
+/// ```text
+/// .....
+/// ..##.
+/// ..#..
+/// .....
+/// ..##.
+/// .....
+/// ```
+///
+/// Returns true if an elf has moved.
+fn advance(grid: &mut Grid, directions: &[Direction]) -> bool {
+    // Maps proposed position to elves, multiple elves may propose the same position
+    let mut proposals: HashMap<Pos, Vec<Pos>> = HashMap::new();
+    let mut moved = false;
+
+    // first phase each elf proposes a new position
+    for elf in &grid.elves {
+        let neighbors: [bool; 8] = grid
+            .neighbors(*elf)
+            .map(|neighbor| grid.elves.contains(&neighbor))
+            .collect_vec()
+            .try_into()
+            .unwrap();
+
+        // If there no elves in the adjacent fields don't move
+        if neighbors.iter().all(|occupied| !occupied) {
+            continue;
+        }
+
+        if let Some(proposed_dir) = directions.iter().find(|&&dir| propose(dir, &neighbors)) {
+            proposals
+                .entry(elf.step(*proposed_dir))
+                .or_default()
+                .push(*elf);
+        }
+    }
+
+    // movement phase
+    for (next_pos, elves) in proposals {
+        if elves.len() == 1 {
+            moved = true;
+            grid.elves.remove(&elves[0]);
+            grid.elves.insert(next_pos);
+        }
+    }
+
+    moved
+}
+
+pub fn part1(mut grid: Grid) -> i64 {
+    let mut directions = vec![
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ];
+
+    for _ in 0..10 {
+        advance(&mut grid, &directions);
+        directions.rotate_left(1);
+    }
+
+    // determine bounds of current arrangement
+    grid.empty_tiles()
+}
+
+pub fn part2(mut grid: Grid) -> i64 {
+    let mut directions = vec![
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ];
+
+    for round in 1.. {
+        if !advance(&mut grid, &directions) {
+            return round;
+        }
+        directions.rotate_left(1);
+    }
+
+    i64::MAX
+}
+
+/// Parses the string, returns a map of monkey id to operation
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    let mut elves = HashSet::new();
+    let lines = input.lines().map(str::trim).filter(|line| !line.is_empty());
+    for (row, line) in lines.enumerate() {
+        for (col, (index, c)) in line.char_indices().enumerate() {
+            match c {
+                '#' => {
+                    elves.insert(Pos::new(col as i32, row as i32));
+                }
+                '.' => (),
+                _ => {
+                    let text = &line[index..index + c.len_utf8()];
+                    return Err(ParseError::at(
+                        input,
+                        text,
+                        "Expected an elf '#' or ground '.'",
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(Grid::new(elves))
+}
+
+/// Day 23: Unstable Diffusion
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Unstable Diffusion";
+
+    type Input<'a> = Grid;
+    type Config = ();
+
+    fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part1(input.clone()).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part2(input.clone()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        ....#..
+        ..###.#
+        #...#.#
+        .#...##
+        #.###..
+        ##.#.##
+        .#..#..
+    ";
+
+    #[test]
+    fn check_advance() {
+        let input: &str = r#"
+            .....
+            ..##.
+            ..#..
+            .....
+            ..##.
+            .....
+        "#;
+
+        let expected: &str = r#"
+            ..##.
+            .....
+            ..#..
+            ...#.
+            ..#..
+            .....
+        "#;
+
+        let mut grid = parse(input).unwrap();
+        let directions = vec![
+            Direction::North,
+            Direction::South,
+            Direction::West,
+            Direction::East,
+        ];
+        advance(&mut grid, &directions);
+        println!("{}", grid);
+
+        let expected_grid = parse(expected).unwrap();
+        assert_eq!(expected_grid, grid);
+    }
+
+    #[test]
+    fn check_advance_big() {
+        let input: &str = r#"
+            ..............
+            .......#......
+            .....#...#....
+            ...#..#.#.....
+            .......#..#...
+            ....#.#.##....
+            ..#..#.#......
+            ..#.#.#.##....
+            ..............
+            ....#..#......
+            ..............
+            ..............
+        "#;
+
+        let expected: &str = r#"
+            ..............
+            .......#......
+            ....#.....#...
+            ...#..#.#.....
+            .......#...#..
+            ...#..#.#.....
+            .#...#.#.#....
+            ..............
+            ..#.#.#.##....
+            ....#..#......
+            ..............
+            ..............
+        "#;
+
+        // simulate 2nd round
+        let directions = vec![
+            Direction::South,
+            Direction::West,
+            Direction::East,
+            Direction::North,
+        ];
+
+        let mut grid = parse(input).unwrap();
+        advance(&mut grid, &directions);
+
+        let expected_grid = parse(expected).unwrap();
+        assert_eq!(expected_grid, grid);
+    }
+
+    #[test]
+    fn check_part1() {
+        assert_eq!(110, part1(parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn check_part2() {
+        assert_eq!(20, part2(parse(INPUT).unwrap()));
+    }
+}
@@ -0,0 +1,259 @@
+//! Day 24: Unstable Diffusion
+
+use std::collections::HashSet;
+
+use aoc_common::{
+    solution::{Answer, Solution},
+    ParseError,
+};
+use aoc_grid::{Direction, Grid, Point};
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum Tile {
+    Ground = 0,
+    Wall,
+}
+
+pub type Pos = Point<i32>;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Blizzard {
+    pos: Pos,
+    dir: Direction,
+}
+
+impl Blizzard {
+    pub fn new(pos: Pos, dir: Direction) -> Self {
+        Self { pos, dir }
+    }
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    /// All tiles of the maze, outline is walls
+    tiles: Grid<Tile>,
+}
+
+impl Maze {
+    pub fn new(tiles: Grid<Tile>) -> Self {
+        Self { tiles }
+    }
+
+    /// The width of the maze
+    fn width(&self) -> i32 {
+        self.tiles.width() as i32
+    }
+
+    /// The height of the maze
+    fn height(&self) -> i32 {
+        self.tiles.height() as i32
+    }
+
+    /// Returns all unique blizzard formations possible.
+    pub fn get_blizzard_formations(&self, blizzards: &[Blizzard]) -> Vec<Vec<Blizzard>> {
+        let lcm = num::integer::lcm(self.width() - 2, self.height() - 2);
+
+        let mut current = blizzards.to_vec();
+        let mut blizzards = Vec::new();
+
+        for _ in 0..lcm {
+            blizzards.push(current.clone());
+            let next_blizzards = self.advance(&current);
+            current = next_blizzards;
+        }
+
+        blizzards
+    }
+
+    /// Search the shortest path
+    pub fn shortest(
+        &self,
+        steps: u32,
+        start: Pos,
+        end: Pos,
+        blizzards: &[Blizzard],
+    ) -> Option<u32> {
+        let blizzards = self.get_blizzard_formations(blizzards);
+        let blizzards_len = blizzards.len() as u32;
+
+        let mut time = steps;
+        let mut current_positions = HashSet::from([start]);
+
+        loop {
+            let mut next_positions: HashSet<Pos> = HashSet::new();
+            let next_blizzrds = &blizzards[(time + 1).rem_euclid(blizzards_len) as usize];
+
+            for pos in current_positions.into_iter() {
+                // wait or move into any direction
+                for next_pos in std::iter::once(pos).chain(pos.neighbours4()) {
+                    if self.get_tile(&next_pos) != Tile::Ground {
+                        continue;
+                    }
+
+                    if next_pos == end {
+                        return Some(time - steps + 1);
+                    }
+
+                    if next_blizzrds.iter().all(|b| b.pos != next_pos) {
+                        next_positions.insert(next_pos);
+                    }
+                }
+            }
+
+            current_positions = next_positions;
+            time += 1;
+        }
+    }
+
+    /// Advances all blizzards
+    pub fn advance(&self, blizzards: &[Blizzard]) -> Vec<Blizzard> {
+        let mut result = Vec::new();
+
+        for Blizzard { pos, dir } in blizzards {
+            let next_pos = pos.step(*dir);
+            let x = (next_pos.x - 1).rem_euclid(self.width() - 2) + 1;
+            let y = (next_pos.y - 1).rem_euclid(self.height() - 2) + 1;
+            result.push(Blizzard::new(Pos::new(x, y), *dir));
+        }
+
+        result
+    }
+
+    fn get_tile(&self, pos: &Pos) -> Tile {
+        self.tiles.get(*pos).copied().unwrap_or(Tile::Wall)
+    }
+
+    fn start(&self) -> Pos {
+        Pos::new(1, 0)
+    }
+
+    fn end(&self) -> Pos {
+        Pos::new(self.width() - 2, self.height() - 1)
+    }
+}
+
+/// Find the shortest path in the maze
+pub fn part1(maze: &Maze, blizzards: &[Blizzard]) -> u32 {
+    maze.shortest(0, maze.start(), maze.end(), blizzards)
+        .expect("Failed to find path")
+}
+
+pub fn part2(maze: &Maze, blizzards: &[Blizzard]) -> u32 {
+    let a = maze
+        .shortest(0, maze.start(), maze.end(), blizzards)
+        .expect("Failed to find path");
+
+    let b = maze
+        .shortest(a, maze.end(), maze.start(), blizzards)
+        .expect("Failed to find path");
+
+    let c = maze
+        .shortest(a + b, maze.start(), maze.end(), blizzards)
+        .expect("Failed to find path");
+
+    a + b + c
+}
+
+/// Parses the string, returns a map of monkey id to operation
+pub fn parse(input: &str) -> Result<(Maze, Vec<Blizzard>), ParseError> {
+    let cells = Grid::parse(input, |c| {
+        matches!(c, '#' | '.' | '>' | '<' | '^' | 'v').then_some(c)
+    })?;
+
+    let blizzards = cells
+        .iter()
+        .filter_map(|(pos, c)| {
+            let dir = match c {
+                '>' => Direction::East,
+                '<' => Direction::West,
+                '^' => Direction::North,
+                'v' => Direction::South,
+                _ => return None,
+            };
+            Some(Blizzard::new(pos, dir))
+        })
+        .collect();
+
+    let tiles = cells.map(|&c| match c {
+        '#' => Tile::Wall,
+        _ => Tile::Ground,
+    });
+
+    Ok((Maze::new(tiles), blizzards))
+}
+
+/// Day 24: Blizzard Basin
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Blizzard Basin";
+
+    type Input<'a> = (Maze, Vec<Blizzard>);
+    type Config = ();
+
+    fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part1(&input.0, &input.1).into())
+    }
+
+    fn part2(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part2(&input.0, &input.1).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        #.######
+        #>>.<^<#
+        #.<..<<#
+        #>v.><>#
+        #<^v^^>#
+        ######.#
+    ";
+
+    #[test]
+    fn check_advance_blizzards() {
+        let input: &str = r#"
+            #.#####
+            #.....#
+            #.>...#
+            #.....#
+            #.....#
+            #...v.#
+            #####.#
+        "#;
+
+        let (maze, blizzards) = parse(input).unwrap();
+        let blizzards = maze.advance(&blizzards);
+
+        let expected = vec![
+            Blizzard::new(Pos::new(3, 2), Direction::East),
+            Blizzard::new(Pos::new(4, 1), Direction::South),
+        ];
+
+        assert_eq!(expected, blizzards,);
+    }
+
+    #[test]
+    fn check_part1() {
+        let (maze, blizzards) = parse(INPUT).unwrap();
+        assert_eq!(18, part1(&maze, &blizzards));
+    }
+
+    #[test]
+    fn check_part2() {
+        let (maze, blizzards) = parse(INPUT).unwrap();
+        assert_eq!(54, part2(&maze, &blizzards));
+    }
+}
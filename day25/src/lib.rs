@@ -0,0 +1,168 @@
+//! Day 25: Full of Hot Air
+
+use aoc_common::{
+    solution::{Answer, Solution},
+    ParseError,
+};
+
+/// Path to the bundled puzzle input.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt");
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct SnafuNumber(pub i64);
+
+impl SnafuNumber {
+    /// Parses the SNAFU number `value`, a sub slice of the puzzle `input`.
+    ///
+    /// The digits are 2, 1, 0, minus (written -), and double-minus (written =).
+    /// Minus is worth -1, and double-minus is worth -2.
+    pub fn parse(input: &str, value: &str) -> Result<Self, ParseError> {
+        let mut number: i64 = 0;
+        for (index, c) in value.char_indices() {
+            let digit: i64 = match c {
+                '2' => 2,
+                '1' => 1,
+                '0' => 0,
+                '-' => -1,
+                '=' => -2,
+                _ => {
+                    let text = &value[index..index + c.len_utf8()];
+                    return Err(ParseError::at(
+                        input,
+                        text,
+                        "Expected a digit 2, 1, 0, - or =",
+                    ));
+                }
+            };
+            number = number * 5 + digit;
+        }
+        Ok(SnafuNumber(number))
+    }
+
+    pub fn to_snafu(&self) -> String {
+        const DIGITS: [char; 5] = ['0', '1', '2', '=', '-'];
+
+        let mut result = Vec::new();
+        let mut decimal = self.0;
+
+        loop {
+            if decimal == 0 {
+                break;
+            }
+
+            let digit = DIGITS[decimal as usize % 5];
+            let new_decimal = (decimal + 2) / 5;
+            result.push(digit);
+            decimal = new_decimal;
+        }
+
+        result.iter().rev().collect()
+    }
+}
+
+impl std::ops::Add for SnafuNumber {
+    type Output = SnafuNumber;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::AddAssign for SnafuNumber {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+/// Find the shortest path in the maze
+pub fn part1(numbers: &[SnafuNumber]) -> String {
+    let result = SnafuNumber(numbers.iter().map(|n| n.0).sum::<i64>());
+    result.to_snafu()
+}
+
+/// Parses the string, returns a map of monkey id to operation
+pub fn parse(input: &str) -> Result<Vec<SnafuNumber>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| SnafuNumber::parse(input, line))
+        .collect()
+}
+
+/// Day 25: Full of Hot Air
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Full of Hot Air";
+    const PARTS: u8 = 1;
+
+    type Input<'a> = Vec<SnafuNumber>;
+    type Config = ();
+
+    fn parse<'a>(input: &'a str, _config: &()) -> anyhow::Result<Self::Input<'a>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input<'_>, _config: &()) -> anyhow::Result<Answer> {
+        Ok(part1(input).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("test.txt");
+
+    fn snafu(value: &str) -> i64 {
+        SnafuNumber::parse(value, value).unwrap().0
+    }
+
+    #[test]
+    fn check_conversion() {
+        assert_eq!(1747, snafu("1=-0-2"));
+        assert_eq!(906, snafu("12111"));
+        assert_eq!(198, snafu("2=0="));
+        assert_eq!(11, snafu("21"));
+        assert_eq!(201, snafu("2=01"));
+        assert_eq!(31, snafu("111"));
+        assert_eq!(1257, snafu("20012"));
+        assert_eq!(32, snafu("112"));
+        assert_eq!(353, snafu("1=-1="));
+        assert_eq!(107, snafu("1-12"));
+        assert_eq!(7, snafu("12"));
+        assert_eq!(3, snafu("1="));
+        assert_eq!(37, snafu("122"));
+    }
+
+    #[test]
+    fn check_parse_error() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                3,
+                "3",
+                "Expected a digit 2, 1, 0, - or ="
+            )),
+            parse("1=\n1-3\n")
+        );
+    }
+
+    #[test]
+    fn test_conversion_to_snafu() {
+        let number = SnafuNumber(4890);
+        assert_eq!("2=-1=0", &number.to_snafu());
+    }
+
+    #[test]
+    fn check_part1() {
+        let result = part1(&parse(INPUT).unwrap());
+        assert_eq!(SnafuNumber(4890).to_snafu(), result);
+    }
+
+    #[ignore]
+    #[test]
+    fn check_part2() {}
+}
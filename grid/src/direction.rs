@@ -0,0 +1,87 @@
+//! The directions of the 4- & 8-neighbourhood.
+
+use crate::{Coordinate, Point};
+
+/// A compass direction, north points up (towards negative y).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Direction {
+    North = 0,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The 4-neighbourhood, clockwise starting north
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The 8-neighbourhood, clockwise starting north
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Returns the unit vector of this direction.
+    pub fn offset<C: Coordinate>(&self) -> Point<C> {
+        let (zero, one) = (C::ZERO, C::ONE);
+        match self {
+            Direction::North => Point::new(zero, -one),
+            Direction::NorthEast => Point::new(one, -one),
+            Direction::East => Point::new(one, zero),
+            Direction::SouthEast => Point::new(one, one),
+            Direction::South => Point::new(zero, one),
+            Direction::SouthWest => Point::new(-one, one),
+            Direction::West => Point::new(-one, zero),
+            Direction::NorthWest => Point::new(-one, -one),
+        }
+    }
+
+    /// Returns the direction rotated by 90° counter clockwise.
+    pub fn turn_left(&self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Returns the direction rotated by 90° clockwise.
+    pub fn turn_right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Returns the opposite direction.
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Rotates clockwise in steps of 45°.
+    fn rotate(&self, steps: u8) -> Self {
+        Self::ALL[((*self as u8 + steps) % 8) as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_turns() {
+        assert_eq!(Direction::West, Direction::North.turn_left());
+        assert_eq!(Direction::East, Direction::North.turn_right());
+        assert_eq!(Direction::SouthWest, Direction::NorthEast.opposite());
+        assert_eq!(Point::new(-1, 1), Direction::SouthWest.offset::<i32>());
+    }
+}
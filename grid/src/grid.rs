@@ -0,0 +1,259 @@
+//! A dense, rectangular grid of cells.
+
+use std::fmt::{Display, Formatter};
+
+use aoc_common::ParseError;
+
+use crate::{Coordinate, Point};
+
+/// A rectangular grid, the cells are stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with all cells set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Creates a grid from rows of different lengths, shorter rows are padded with `fill`.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let rows = rows.into_iter().collect::<Vec<_>>();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let height = rows.len();
+
+        let mut cells = Vec::with_capacity(width * height);
+        for mut row in rows {
+            row.resize(width, fill.clone());
+            cells.append(&mut row);
+        }
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid from ASCII art, each character is mapped to a cell by `f`.
+    ///
+    /// Lines are trimmed, empty lines are skipped. All rows need to have the same width.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let mut found = 0;
+            for (index, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| {
+                    let text = &line[index..index + c.len_utf8()];
+                    ParseError::at(input, text, "Unknown cell")
+                })?;
+                cells.push(cell);
+                found += 1;
+            }
+
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                let message = format!("Expected a row of {} cells, found {}", expected, found);
+                return Err(ParseError::at(input, line, message));
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// The number of columns
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the index of the cell, `None` if the point is outside of the grid.
+    fn index<C: Coordinate>(&self, pos: Point<C>) -> Option<usize> {
+        let (x, y) = (pos.x.to_index()?, pos.y.to_index()?);
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    /// Returns the point of the cell with the given index.
+    fn point<C: Coordinate>(&self, index: usize) -> Point<C> {
+        let x = C::from_index(index % self.width).expect("Column does not fit coordinate");
+        let y = C::from_index(index / self.width).expect("Row does not fit coordinate");
+        Point::new(x, y)
+    }
+
+    /// Returns true if the point is inside the grid.
+    pub fn contains<C: Coordinate>(&self, pos: Point<C>) -> bool {
+        self.index(pos).is_some()
+    }
+
+    /// Returns the cell, `None` if the point is outside of the grid.
+    pub fn get<C: Coordinate>(&self, pos: Point<C>) -> Option<&T> {
+        self.index(pos).map(|index| &self.cells[index])
+    }
+
+    /// Returns the mutable cell, `None` if the point is outside of the grid.
+    pub fn get_mut<C: Coordinate>(&mut self, pos: Point<C>) -> Option<&mut T> {
+        self.index(pos).map(|index| &mut self.cells[index])
+    }
+
+    /// Returns all cells with their points, row by row.
+    pub fn iter<C: Coordinate>(&self) -> impl Iterator<Item = (Point<C>, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.point(index), cell))
+    }
+
+    /// Returns all points of the grid, row by row.
+    pub fn points<C: Coordinate>(&self) -> impl Iterator<Item = Point<C>> + '_ {
+        (0..self.cells.len()).map(|index| self.point(index))
+    }
+
+    /// Returns the point of the first cell that matches, searches row by row.
+    pub fn find<C: Coordinate>(&self, predicate: impl Fn(&T) -> bool) -> Option<Point<C>> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.point(index))
+    }
+
+    /// Returns the orthogonally adjacent points that are inside the grid.
+    pub fn neighbours4<C: Coordinate>(&self, pos: Point<C>) -> impl Iterator<Item = Point<C>> + '_ {
+        pos.neighbours4()
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// Returns all adjacent points, including diagonals, that are inside the grid.
+    pub fn neighbours8<C: Coordinate>(&self, pos: Point<C>) -> impl Iterator<Item = Point<C>> + '_ {
+        pos.neighbours8()
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// Returns a grid of the same size with each cell mapped by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Returns the rows of the grid.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Renders the grid as ASCII art, each cell is mapped to a character by `f`.
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            output.extend(row.iter().map(&f));
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"
+        #..
+        .#.
+        ..#
+    "#;
+
+    fn parse_cell(c: char) -> Option<bool> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn check_parse_and_render() {
+        let grid = Grid::parse(INPUT, parse_cell).unwrap();
+        assert_eq!((3, 3), (grid.width(), grid.height()));
+        assert_eq!(Some(&true), grid.get(Point::new(1, 1)));
+        assert_eq!(None, grid.get(Point::new(-1, 1)));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(
+            "#..\n.#.\n..#\n",
+            grid.render(|&c| if c { '#' } else { '.' })
+        );
+    }
+
+    #[test]
+    fn check_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(2, 2, "x", "Unknown cell")),
+            Grid::parse("#.\n.x", parse_cell)
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                1,
+                ".##",
+                "Expected a row of 2 cells, found 3"
+            )),
+            Grid::parse("#.\n.##", parse_cell)
+        );
+    }
+
+    #[test]
+    fn check_neighbours() {
+        let grid = Grid::new(3, 2, 0u8);
+        assert_eq!(2, grid.neighbours4(Point::new(0, 0)).count());
+        assert_eq!(3, grid.neighbours4(Point::new(1, 0)).count());
+        assert_eq!(5, grid.neighbours8(Point::new(1, 1)).count());
+    }
+
+    #[test]
+    fn check_from_rows() {
+        let grid = Grid::from_rows(vec![vec![1], vec![1, 2, 3]], 0);
+        assert_eq!(
+            vec![(Point::new(0, 0), &1), (Point::new(1, 0), &0)],
+            grid.iter::<i32>().take(2).collect::<Vec<_>>()
+        );
+        assert_eq!(Some(Point::new(2, 1)), grid.find::<i32>(|&c| c == 3));
+    }
+}
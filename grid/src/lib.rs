@@ -0,0 +1,13 @@
+//! Two dimensional geometry shared by the puzzle days.
+//!
+//! Provides a generic [`Point`], the [`Direction`]s of the 4- & 8-neighbourhood and a dense
+//! [`Grid`] that is parsed from & rendered to ASCII art. The y axis points downwards, the
+//! first line of the input is row `0`.
+
+mod direction;
+mod grid;
+mod point;
+
+pub use direction::Direction;
+pub use grid::Grid;
+pub use point::{Coordinate, Point};
@@ -0,0 +1,218 @@
+//! A generic two dimensional point.
+
+use std::{
+    fmt::{Debug, Display, Formatter},
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::Direction;
+
+/// The signed integer types a [`Point`] can be made of.
+pub trait Coordinate:
+    'static
+    + Copy
+    + Ord
+    + Hash
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Converts a grid index into a coordinate, `None` if it does not fit.
+    fn from_index(index: usize) -> Option<Self>;
+
+    /// Converts the coordinate into a grid index, `None` if it's negative.
+    fn to_index(self) -> Option<usize>;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),+) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn from_index(index: usize) -> Option<Self> {
+                    Self::try_from(index).ok()
+                }
+
+                fn to_index(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+            }
+        )+
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, isize);
+
+/// A point or a vector on a two dimensional plane.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<C> {
+    pub x: C,
+    pub y: C,
+}
+
+impl<C> Point<C> {
+    pub const fn new(x: C, y: C) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<C: Coordinate> Point<C> {
+    /// The origin `(0, 0)`
+    pub const ORIGIN: Self = Self::new(C::ZERO, C::ZERO);
+
+    /// Returns the Manhattan distance to the other point.
+    pub fn manhattan(&self, rhs: &Self) -> C {
+        let diff = *self - *rhs;
+        abs(diff.x) + abs(diff.y)
+    }
+
+    /// Returns the Chebyshev distance to the other point, `1` for all 8 adjacent points.
+    pub fn chebyshev(&self, rhs: &Self) -> C {
+        let diff = *self - *rhs;
+        abs(diff.x).max(abs(diff.y))
+    }
+
+    /// Returns the vector with both components reduced to `-1`, `0` or `1`.
+    pub fn signum(&self) -> Self {
+        Self::new(signum(self.x), signum(self.y))
+    }
+
+    /// Returns the adjacent point in the given direction.
+    pub fn step(&self, dir: Direction) -> Self {
+        *self + dir.offset()
+    }
+
+    /// Returns the 4 orthogonally adjacent points, clockwise starting north.
+    pub fn neighbours4(&self) -> impl Iterator<Item = Self> {
+        let pos = *self;
+        Direction::ORTHOGONAL.iter().map(move |&dir| pos.step(dir))
+    }
+
+    /// Returns all 8 adjacent points, clockwise starting north.
+    pub fn neighbours8(&self) -> impl Iterator<Item = Self> {
+        let pos = *self;
+        Direction::ALL.iter().map(move |&dir| pos.step(dir))
+    }
+}
+
+fn abs<C: Coordinate>(value: C) -> C {
+    if value < C::ZERO {
+        -value
+    } else {
+        value
+    }
+}
+
+fn signum<C: Coordinate>(value: C) -> C {
+    match value.cmp(&C::ZERO) {
+        std::cmp::Ordering::Less => -C::ONE,
+        std::cmp::Ordering::Equal => C::ZERO,
+        std::cmp::Ordering::Greater => C::ONE,
+    }
+}
+
+impl<C> From<(C, C)> for Point<C> {
+    fn from((x, y): (C, C)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<C: Display> Display for Point<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+impl<C: Coordinate> Add for Point<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<C: Coordinate> AddAssign for Point<C> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<C: Coordinate> Sub for Point<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<C: Coordinate> SubAssign for Point<C> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<C: Coordinate> Neg for Point<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<C: Coordinate> Mul<C> for Point<C> {
+    type Output = Self;
+
+    fn mul(self, rhs: C) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_arithmetic() {
+        let p = Point::new(3, -2);
+        assert_eq!(Point::new(4, 0), p + Point::new(1, 2));
+        assert_eq!(Point::new(2, -4), p - Point::new(1, 2));
+        assert_eq!(Point::new(-6, 4), -p * 2);
+        assert_eq!(Point::new(1, -1), p.signum());
+    }
+
+    #[test]
+    fn check_distances() {
+        let p = Point::new(1i64, 1);
+        assert_eq!(5, p.manhattan(&Point::new(-1, 4)));
+        assert_eq!(3, p.chebyshev(&Point::new(-1, 4)));
+        assert_eq!(1, p.chebyshev(&Point::new(2, 2)));
+    }
+
+    #[test]
+    fn check_neighbours() {
+        let p = Point::new(0, 0);
+        assert_eq!(
+            vec![
+                Point::new(0, -1),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(-1, 0)
+            ],
+            p.neighbours4().collect::<Vec<_>>()
+        );
+        assert_eq!(8, p.neighbours8().count());
+    }
+}